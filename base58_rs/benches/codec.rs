@@ -0,0 +1,141 @@
+extern crate base58_rs;
+#[macro_use]
+extern crate criterion;
+extern crate num_bigint;
+extern crate num_integer;
+extern crate num_traits;
+
+use criterion::{BenchmarkId, Criterion, Throughput};
+
+const SIZES: [usize; 3] = [32, 1024, 64 * 1024];
+
+mod bignum {
+    use num_bigint::{BigUint, ToBigUint};
+    use num_integer::Integer;
+    use num_traits::{ToPrimitive, Zero};
+
+    const CHARACTERS: &[u8] = b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz";
+
+    pub fn encode(plain_bytes: &[u8]) -> String {
+        let zero_count = plain_bytes.iter().take_while(|&&byte| byte == 0).count();
+
+        let value_256 = plain_bytes[zero_count..]
+            .iter()
+            .fold(Zero::zero(), |acc: BigUint, byte| {
+                acc * 256_usize + byte.to_biguint().unwrap()
+            });
+
+        let mut coefficients_58 = vec![];
+        let mut value = value_256;
+
+        let divisor = BigUint::from(58_usize);
+
+        while !value.is_zero() {
+            coefficients_58.push(value.mod_floor(&divisor).to_usize().unwrap());
+
+            value = value.div_floor(&divisor);
+        }
+
+        coefficients_58.resize(coefficients_58.len() + zero_count, 0);
+        coefficients_58.reverse();
+
+        coefficients_58
+            .into_iter()
+            .map(|coefficient| char::from(CHARACTERS[coefficient]))
+            .collect()
+    }
+
+    pub fn decode(encoded_bytes: &[u8]) -> Option<Vec<u8>> {
+        let zero_count = encoded_bytes
+            .iter()
+            .take_while(|&&ch| ch == CHARACTERS[0])
+            .count();
+
+        let value_58 =
+            encoded_bytes[zero_count..]
+                .iter()
+                .try_fold(Zero::zero(), |acc: BigUint, ch| {
+                    CHARACTERS
+                        .iter()
+                        .position(|v| v == ch)
+                        .map(|value| acc * 58_usize + value)
+                })?;
+
+        let mut coefficients_256 = vec![];
+        let mut value = value_58;
+
+        let divisor = BigUint::from(256_usize);
+
+        while !value.is_zero() {
+            coefficients_256.push(value.mod_floor(&divisor).to_u8().unwrap());
+
+            value = value.div_floor(&divisor);
+        }
+
+        coefficients_256.resize(coefficients_256.len() + zero_count, 0);
+        coefficients_256.reverse();
+
+        Some(coefficients_256)
+    }
+}
+
+fn sample_bytes(length: usize) -> Vec<u8> {
+    let mut state: u32 = 0x9e37_79b9;
+
+    (0..length)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+
+            state as u8
+        })
+        .collect()
+}
+
+fn encode_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("encode");
+    group.sample_size(10);
+
+    for &size in SIZES.iter() {
+        let plain_bytes = sample_bytes(size);
+
+        group.throughput(Throughput::Bytes(size as u64));
+
+        group.bench_with_input(BenchmarkId::new("limbs", size), &plain_bytes, |b, bytes| {
+            b.iter(|| base58_rs::encode(bytes))
+        });
+        group.bench_with_input(
+            BenchmarkId::new("bignum", size),
+            &plain_bytes,
+            |b, bytes| b.iter(|| bignum::encode(bytes)),
+        );
+    }
+
+    group.finish();
+}
+
+fn decode_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("decode");
+    group.sample_size(10);
+
+    for &size in SIZES.iter() {
+        let encoded_text = base58_rs::encode(sample_bytes(size));
+
+        group.throughput(Throughput::Bytes(encoded_text.len() as u64));
+
+        group.bench_with_input(BenchmarkId::new("limbs", size), &encoded_text, |b, text| {
+            b.iter(|| base58_rs::decode(text))
+        });
+        group.bench_with_input(
+            BenchmarkId::new("bignum", size),
+            &encoded_text,
+            |b, text| b.iter(|| bignum::decode(text.as_bytes())),
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, encode_benchmark, decode_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,33 @@
+#![no_main]
+
+use base58_rs::{check, monero, Alphabet, BaseN};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let decoded = base58_rs::decode(data);
+
+    assert_eq!(
+        base58_rs::validate(data),
+        decoded.as_ref().map(|_| ()).map_err(|error| *error)
+    );
+
+    if let Ok(plain_bytes) = decoded {
+        assert_eq!(base58_rs::encode(&plain_bytes).as_bytes(), data);
+    }
+
+    let mut output = [0; 64];
+    let _ = base58_rs::decode_to_slice(data, &mut output);
+
+    for alphabet in &[Alphabet::RIPPLE, Alphabet::FLICKR] {
+        let _ = base58_rs::decode_with_alphabet(data, alphabet);
+    }
+
+    for base in &[BaseN::BASE36, BaseN::BASE62] {
+        let _ = base.decode(data);
+    }
+
+    let _ = check::decode(data);
+    let _ = check::validate(data, 0x00);
+    let _ = monero::decode(data);
+    let _ = base58_rs::decode_string(&String::from_utf8_lossy(data));
+});
@@ -0,0 +1,14 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use rust_base58::FromBase58;
+
+fuzz_target!(|text: &str| {
+    let decoded = base58_rs::decode(text);
+
+    assert_eq!(decoded.as_ref().ok(), text.from_base58().ok().as_ref());
+
+    if let Ok(plain_bytes) = decoded {
+        assert_eq!(base58_rs::encode(plain_bytes), text);
+    }
+});
@@ -0,0 +1,32 @@
+#![no_main]
+
+use base58_rs::ascii85::Ascii85;
+use base58_rs::BaseN;
+use libfuzzer_sys::fuzz_target;
+use rust_base58::ToBase58;
+
+fuzz_target!(|data: &[u8]| {
+    let encoded_text = base58_rs::encode(data);
+
+    assert_eq!(encoded_text, data.to_base58());
+    assert!(encoded_text.len() <= base58_rs::max_encoded_len(data.len()));
+    assert_eq!(Ok(data.to_vec()), base58_rs::decode(&encoded_text));
+
+    let mut output = vec![0; encoded_text.len()];
+
+    assert_eq!(
+        Ok(encoded_text.len()),
+        base58_rs::encode_to_slice(data, &mut output)
+    );
+    assert_eq!(encoded_text.as_bytes(), &output[..]);
+
+    for base in &[BaseN::BASE36, BaseN::BASE58, BaseN::BASE62] {
+        let mut output = vec![0; base.max_encoded_len(data.len())];
+
+        assert!(base.encode_to_slice(data, &mut output).is_ok());
+    }
+
+    for ascii85 in &[Ascii85::STANDARD, Ascii85::ADOBE] {
+        assert!(ascii85.encode(data).len() <= ascii85.max_encoded_len(data.len()));
+    }
+});
@@ -0,0 +1,140 @@
+use core::fmt;
+#[cfg(feature = "std")]
+use std::error::Error;
+
+use super::BaseN;
+
+pub const ALPHABET_LENGTH: usize = 58;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Alphabet {
+    base: BaseN,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlphabetError {
+    InvalidLength(usize),
+    NonAsciiCharacter { index: usize, byte: u8 },
+    DuplicateCharacter { index: usize, character: char },
+}
+
+impl Alphabet {
+    pub const BITCOIN: Alphabet =
+        Alphabet::from_characters(*b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz");
+
+    pub const RIPPLE: Alphabet =
+        Alphabet::from_characters(*b"rpshnaf39wBUDNEGHJKLM4PQRST7VWXYZ2bcdeCg65jkm8oFqi1tuvAxyz");
+
+    pub const FLICKR: Alphabet =
+        Alphabet::from_characters(*b"123456789abcdefghijkmnopqrstuvwxyzABCDEFGHJKLMNPQRSTUVWXYZ");
+
+    pub fn new<T: AsRef<[u8]>>(characters: T) -> Result<Self, AlphabetError> {
+        let characters = characters.as_ref();
+
+        if characters.len() != ALPHABET_LENGTH {
+            return Err(AlphabetError::InvalidLength(characters.len()));
+        }
+
+        let mut alphabet_characters = [0; ALPHABET_LENGTH];
+
+        for (index, &byte) in characters.iter().enumerate() {
+            if !byte.is_ascii() {
+                return Err(AlphabetError::NonAsciiCharacter { index, byte });
+            }
+
+            if characters[..index].contains(&byte) {
+                return Err(AlphabetError::DuplicateCharacter {
+                    index,
+                    character: char::from(byte),
+                });
+            }
+
+            alphabet_characters[index] = byte;
+        }
+
+        Ok(Alphabet::from_characters(alphabet_characters))
+    }
+
+    const fn from_characters(characters: [u8; ALPHABET_LENGTH]) -> Self {
+        Alphabet {
+            base: BaseN::from_characters(&characters),
+        }
+    }
+
+    pub fn characters(&self) -> &[u8] {
+        self.base.characters()
+    }
+
+    pub fn base(&self) -> &BaseN {
+        &self.base
+    }
+}
+
+impl Default for Alphabet {
+    fn default() -> Self {
+        Alphabet::BITCOIN
+    }
+}
+
+impl From<Alphabet> for BaseN {
+    fn from(alphabet: Alphabet) -> Self {
+        alphabet.base
+    }
+}
+
+impl fmt::Display for AlphabetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AlphabetError::InvalidLength(length) => {
+                write!(f, "alphabet has an invalid length of {} characters", length)
+            }
+            AlphabetError::NonAsciiCharacter { index, byte } => {
+                write!(f, "non-ASCII byte {:#04x} at index {}", byte, index)
+            }
+            AlphabetError::DuplicateCharacter { index, character } => {
+                write!(f, "duplicate character {:?} at index {}", character, index)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for AlphabetError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn builtin_alphabets_should_be_valid() {
+        for alphabet in &[Alphabet::BITCOIN, Alphabet::RIPPLE, Alphabet::FLICKR] {
+            assert_eq!(Ok(*alphabet), Alphabet::new(alphabet.characters()));
+        }
+    }
+
+    #[test]
+    fn invalid_alphabets_should_fail() {
+        assert_eq!(
+            Err(AlphabetError::InvalidLength(10)),
+            Alphabet::new("0123456789")
+        );
+        assert_eq!(
+            Err(AlphabetError::DuplicateCharacter {
+                index: 57,
+                character: '1'
+            }),
+            Alphabet::new("123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxy1")
+        );
+
+        let mut non_ascii = Alphabet::BITCOIN.characters().to_vec();
+        non_ascii[3] = 0xb1;
+
+        assert_eq!(
+            Err(AlphabetError::NonAsciiCharacter {
+                index: 3,
+                byte: 0xb1
+            }),
+            Alphabet::new(non_ascii)
+        );
+    }
+}
@@ -0,0 +1,366 @@
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec, vec::Vec};
+
+use super::{DecodeError, EncodeError};
+
+const FIRST_CHARACTER: u8 = b'!';
+const LAST_CHARACTER: u8 = b'u';
+const ZERO_GROUP: u8 = b'z';
+const PREFIX: &[u8] = b"<~";
+const SUFFIX: &[u8] = b"~>";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ascii85 {
+    delimiters: bool,
+}
+
+impl Ascii85 {
+    pub const STANDARD: Ascii85 = Ascii85 { delimiters: false };
+
+    pub const ADOBE: Ascii85 = Ascii85 { delimiters: true };
+
+    pub fn max_encoded_len(&self, input_length: usize) -> usize {
+        let remainder_length = match input_length % 4 {
+            0 => 0,
+            remainder => remainder + 1,
+        };
+        let delimiter_length = if self.delimiters {
+            PREFIX.len() + SUFFIX.len()
+        } else {
+            0
+        };
+
+        input_length / 4 * 5 + remainder_length + delimiter_length
+    }
+
+    pub fn max_decoded_len(&self, input_length: usize) -> usize {
+        input_length * 4
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn encode<T: AsRef<[u8]>>(&self, input: T) -> String {
+        let plain_bytes = input.as_ref();
+
+        let mut encoded_bytes = vec![0; self.max_encoded_len(plain_bytes.len())];
+
+        let length = match self.encode_to_slice(plain_bytes, &mut encoded_bytes) {
+            Ok(length) => length,
+            Err(EncodeError::BufferTooSmall) => {
+                unreachable!("encoding is longer than max_encoded_len")
+            }
+            Err(EncodeError::InvalidLength) => unreachable!("Ascii85 encodes input of any length"),
+        };
+
+        encoded_bytes[..length]
+            .iter()
+            .map(|&ch| char::from(ch))
+            .collect()
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn decode<T: AsRef<[u8]>>(&self, input: T) -> Result<Vec<u8>, DecodeError> {
+        let encoded_bytes = input.as_ref();
+
+        let mut plain_bytes = vec![0; self.max_decoded_len(encoded_bytes.len())];
+        let length = self.decode_to_slice(encoded_bytes, &mut plain_bytes)?;
+
+        plain_bytes.truncate(length);
+
+        Ok(plain_bytes)
+    }
+
+    pub fn encode_to_slice<T: AsRef<[u8]>>(
+        &self,
+        input: T,
+        output: &mut [u8],
+    ) -> Result<usize, EncodeError> {
+        let plain_bytes = input.as_ref();
+        let mut length = 0;
+
+        if self.delimiters {
+            write(output, &mut length, PREFIX).ok_or(EncodeError::BufferTooSmall)?;
+        }
+
+        for group in plain_bytes.chunks(4) {
+            let mut block = [0; 4];
+            block[..group.len()].copy_from_slice(group);
+
+            let mut value = u32::from_be_bytes(block);
+
+            if group.len() == 4 && value == 0 {
+                write(output, &mut length, &[ZERO_GROUP]).ok_or(EncodeError::BufferTooSmall)?;
+                continue;
+            }
+
+            let mut encoded_group = [0; 5];
+
+            for ch in encoded_group.iter_mut().rev() {
+                *ch = FIRST_CHARACTER + (value % 85) as u8;
+                value /= 85;
+            }
+
+            write(output, &mut length, &encoded_group[..group.len() + 1])
+                .ok_or(EncodeError::BufferTooSmall)?;
+        }
+
+        if self.delimiters {
+            write(output, &mut length, SUFFIX).ok_or(EncodeError::BufferTooSmall)?;
+        }
+
+        Ok(length)
+    }
+
+    // NOTE: Whitespace is skipped anywhere, as PostScript and PDF wrap long Ascii85 lines. With
+    // delimiters the leading `<~` is optional, since PDF streams only keep the trailing `~>`.
+    pub fn decode_to_slice<T: AsRef<[u8]>>(
+        &self,
+        input: T,
+        output: &mut [u8],
+    ) -> Result<usize, DecodeError> {
+        let encoded_bytes = input.as_ref();
+
+        let mut start = 0;
+        let mut end = encoded_bytes.len();
+
+        if self.delimiters {
+            end -= encoded_bytes
+                .iter()
+                .rev()
+                .take_while(|ch| ch.is_ascii_whitespace())
+                .count();
+
+            if !encoded_bytes[..end].ends_with(SUFFIX) {
+                return Err(DecodeError::InvalidLength);
+            }
+
+            end -= SUFFIX.len();
+
+            start = encoded_bytes[..end]
+                .iter()
+                .take_while(|ch| ch.is_ascii_whitespace())
+                .count();
+
+            if encoded_bytes[start..end].starts_with(PREFIX) {
+                start += PREFIX.len();
+            }
+        }
+
+        let mut length = 0;
+        let mut digits = [0; 5];
+        let mut digit_count = 0;
+        let mut group_index = start;
+
+        for (index, &ch) in encoded_bytes.iter().enumerate().take(end).skip(start) {
+            if ch.is_ascii_whitespace() {
+                continue;
+            }
+
+            if ch == ZERO_GROUP && digit_count == 0 {
+                write(output, &mut length, &[0; 4]).ok_or(DecodeError::BufferTooSmall)?;
+                continue;
+            }
+
+            if !(FIRST_CHARACTER..=LAST_CHARACTER).contains(&ch) {
+                return Err(DecodeError::invalid_character(encoded_bytes, index));
+            }
+
+            if digit_count == 0 {
+                group_index = index;
+            }
+
+            digits[digit_count] = ch - FIRST_CHARACTER;
+            digit_count += 1;
+
+            if digit_count == 5 {
+                let block = decode_group(&digits, group_index)?;
+
+                write(output, &mut length, &block).ok_or(DecodeError::BufferTooSmall)?;
+                digit_count = 0;
+            }
+        }
+
+        match digit_count {
+            0 => {}
+            1 => return Err(DecodeError::InvalidLength),
+            _ => {
+                for digit in digits[digit_count..].iter_mut() {
+                    *digit = LAST_CHARACTER - FIRST_CHARACTER;
+                }
+
+                let block = decode_group(&digits, group_index)?;
+
+                write(output, &mut length, &block[..digit_count - 1])
+                    .ok_or(DecodeError::BufferTooSmall)?;
+            }
+        }
+
+        Ok(length)
+    }
+}
+
+impl Default for Ascii85 {
+    fn default() -> Self {
+        Ascii85::STANDARD
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub fn encode<T: AsRef<[u8]>>(input: T) -> String {
+    Ascii85::STANDARD.encode(input)
+}
+
+#[cfg(feature = "alloc")]
+pub fn decode<T: AsRef<[u8]>>(input: T) -> Result<Vec<u8>, DecodeError> {
+    Ascii85::STANDARD.decode(input)
+}
+
+fn decode_group(digits: &[u8; 5], index: usize) -> Result<[u8; 4], DecodeError> {
+    let value = digits
+        .iter()
+        .fold(0_u64, |acc, &digit| acc * 85 + u64::from(digit));
+
+    if value > u64::from(u32::MAX) {
+        return Err(DecodeError::BlockOverflow { index });
+    }
+
+    Ok((value as u32).to_be_bytes())
+}
+
+pub(crate) fn write(output: &mut [u8], length: &mut usize, bytes: &[u8]) -> Option<()> {
+    output
+        .get_mut(*length..*length + bytes.len())?
+        .copy_from_slice(bytes);
+
+    *length += bytes.len();
+
+    Some(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn static_examples_should_work() {
+        let examples: [(&[u8], &str); 8] = [
+            (b"", ""),
+            (b"h", "BE"),
+            (b"hello world", "BOu!rD]j7BEbo7"),
+            (b"Man is distinguished", "9jqo^BlbD-BleB1DJ+*+F(f,q"),
+            (b"\0\0\0\0", "z"),
+            (b"\0\0\0", "!!!!"),
+            (b"\0\0\0\0\0abc", "z!+B>H"),
+            (b"\xff\xff\xff\xff", "s8W-!"),
+        ];
+
+        for (plain_bytes, encoded_text) in examples.iter() {
+            assert_eq!(*encoded_text, encode(plain_bytes));
+            assert_eq!(Ok(plain_bytes.to_vec()), decode(encoded_text));
+
+            let delimited_text = format!("<~{}~>", encoded_text);
+
+            assert_eq!(delimited_text, Ascii85::ADOBE.encode(plain_bytes));
+            assert_eq!(
+                Ok(plain_bytes.to_vec()),
+                Ascii85::ADOBE.decode(&delimited_text)
+            );
+        }
+    }
+
+    #[test]
+    fn delimiters_and_whitespace_should_be_handled() {
+        assert_eq!(
+            Ok(b"hello world".to_vec()),
+            Ascii85::ADOBE.decode("BOu!rD]j7BEbo7~>")
+        );
+        assert_eq!(
+            Ok(b"hello world".to_vec()),
+            Ascii85::ADOBE.decode("<~BOu!r\nD]j7B Ebo7~>\r\n")
+        );
+        assert_eq!(
+            Ok(b"Hello World!".to_vec()),
+            Ascii85::ADOBE.decode("  <~87cURD]i,\"Ebo80~>")
+        );
+        assert_eq!(
+            Ok(b"Hello World!".to_vec()),
+            Ascii85::ADOBE.decode("\r\n<~87cURD]i,\"Ebo80~>\n")
+        );
+        assert_eq!(Ok(b"hello world".to_vec()), decode(" BOu!r D]j7\tBEbo7 "));
+        assert_eq!(
+            Err(DecodeError::InvalidLength),
+            Ascii85::ADOBE.decode("<~BOu!rD]j7BEbo7")
+        );
+    }
+
+    #[test]
+    fn invalid_examples_should_fail() {
+        assert_eq!(Err(DecodeError::InvalidLength), decode("B"));
+        assert_eq!(Err(DecodeError::InvalidLength), decode("BOu!rD"));
+        assert_eq!(
+            Err(DecodeError::BlockOverflow { index: 1 }),
+            decode("zs8W-\"")
+        );
+        assert_eq!(
+            Err(DecodeError::InvalidCharacter {
+                character: 'z',
+                index: 2
+            }),
+            decode("BOz!r")
+        );
+        assert_eq!(
+            Err(DecodeError::InvalidCharacter {
+                character: 'v',
+                index: 4
+            }),
+            Ascii85::ADOBE.decode("<~BOvu~>")
+        );
+    }
+
+    #[test]
+    fn static_slice_examples_should_work() {
+        let mut output = [0; 32];
+
+        assert_eq!(
+            Ok(18),
+            Ascii85::ADOBE.encode_to_slice("hello world", &mut output)
+        );
+        assert_eq!(b"<~BOu!rD]j7BEbo7~>", &output[..18]);
+        assert_eq!(
+            Err(EncodeError::BufferTooSmall),
+            Ascii85::ADOBE.encode_to_slice("hello world", &mut output[..17])
+        );
+
+        assert_eq!(Ok(11), decode_slice("BOu!rD]j7BEbo7", &mut output));
+        assert_eq!(b"hello world", &output[..11]);
+        assert_eq!(
+            Err(DecodeError::BufferTooSmall),
+            decode_slice("BOu!rD]j7BEbo7", &mut output[..10])
+        );
+    }
+
+    fn decode_slice(input: &str, output: &mut [u8]) -> Result<usize, DecodeError> {
+        Ascii85::STANDARD.decode_to_slice(input, output)
+    }
+
+    #[quickcheck]
+    fn encode_decode_should_work(bytes: Vec<u8>) -> bool {
+        [Ascii85::STANDARD, Ascii85::ADOBE].iter().all(|ascii85| {
+            let encoded_text = ascii85.encode(&bytes);
+
+            encoded_text.len() <= ascii85.max_encoded_len(bytes.len())
+                && ascii85.decode(&encoded_text) == Ok(bytes.clone())
+        })
+    }
+
+    #[quickcheck]
+    fn zero_groups_should_be_compressed(zero_count: u8, bytes: Vec<u8>) -> bool {
+        let mut plain_bytes = vec![0; 4 * usize::from(zero_count % 8)];
+        plain_bytes.extend(bytes);
+
+        let encoded_text = encode(&plain_bytes);
+
+        encoded_text.starts_with(&"z".repeat(usize::from(zero_count % 8)))
+            && decode(&encoded_text) == Ok(plain_bytes)
+    }
+}
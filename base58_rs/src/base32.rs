@@ -0,0 +1,449 @@
+use alloc::{string::String, vec::Vec};
+
+use super::{AlphabetError, DecodeError};
+
+pub const ALPHABET_LENGTH: usize = 32;
+
+const INVALID_VALUE: u8 = 0xff;
+const PADDING: u8 = b'=';
+const CHECK_MODULUS: u32 = 37;
+const CHECK_SYMBOLS: [u8; 5] = *b"*~$=U";
+const CROCKFORD_SEPARATOR: u8 = b'-';
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Base32 {
+    characters: [u8; ALPHABET_LENGTH],
+    values: [u8; 256],
+    padding: bool,
+    crockford: bool,
+    check_symbol: bool,
+}
+
+impl Base32 {
+    pub const STANDARD: Base32 =
+        Base32::from_characters(*b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567", true);
+
+    pub const STANDARD_NO_PAD: Base32 = Base32::STANDARD.with_padding(false);
+
+    pub const HEX: Base32 = Base32::from_characters(*b"0123456789ABCDEFGHIJKLMNOPQRSTUV", true);
+
+    pub const HEX_NO_PAD: Base32 = Base32::HEX.with_padding(false);
+
+    pub const CROCKFORD: Base32 = Base32::crockford(false);
+
+    pub const CROCKFORD_WITH_CHECK: Base32 = Base32::crockford(true);
+
+    pub fn new<T: AsRef<[u8]>>(characters: T, padding: bool) -> Result<Self, AlphabetError> {
+        let characters = characters.as_ref();
+
+        if characters.len() != ALPHABET_LENGTH {
+            return Err(AlphabetError::InvalidLength(characters.len()));
+        }
+
+        let mut alphabet_characters = [0; ALPHABET_LENGTH];
+
+        for (index, &byte) in characters.iter().enumerate() {
+            if !byte.is_ascii() {
+                return Err(AlphabetError::NonAsciiCharacter { index, byte });
+            }
+
+            if characters[..index].contains(&byte) || (padding && byte == PADDING) {
+                return Err(AlphabetError::DuplicateCharacter {
+                    index,
+                    character: char::from(byte),
+                });
+            }
+
+            alphabet_characters[index] = byte;
+        }
+
+        Ok(Base32::from_characters(alphabet_characters, padding))
+    }
+
+    pub(crate) const fn from_characters(characters: [u8; ALPHABET_LENGTH], padding: bool) -> Self {
+        let mut values = [INVALID_VALUE; 256];
+        let mut index = 0;
+
+        while index < ALPHABET_LENGTH {
+            values[characters[index] as usize] = index as u8;
+            index += 1;
+        }
+
+        Base32 {
+            characters,
+            values,
+            padding,
+            crockford: false,
+            check_symbol: false,
+        }
+    }
+
+    const fn crockford(check_symbol: bool) -> Self {
+        let mut base = Base32::from_characters(*b"0123456789ABCDEFGHJKMNPQRSTVWXYZ", false);
+        let mut index = 0;
+
+        while index < ALPHABET_LENGTH {
+            let ch = base.characters[index];
+            base.values[ch.to_ascii_lowercase() as usize] = index as u8;
+            index += 1;
+        }
+
+        base.values[b'O' as usize] = 0;
+        base.values[b'o' as usize] = 0;
+        base.values[b'I' as usize] = 1;
+        base.values[b'i' as usize] = 1;
+        base.values[b'L' as usize] = 1;
+        base.values[b'l' as usize] = 1;
+
+        base.crockford = true;
+        base.check_symbol = check_symbol;
+
+        base
+    }
+
+    pub const fn with_padding(mut self, padding: bool) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    pub fn characters(&self) -> &[u8] {
+        &self.characters
+    }
+
+    pub fn encoded_len(&self, input_length: usize) -> usize {
+        let symbol_count = if self.padding {
+            input_length.div_ceil(5) * 8
+        } else {
+            (input_length * 8).div_ceil(5)
+        };
+
+        symbol_count + usize::from(self.check_symbol)
+    }
+
+    pub fn encode<T: AsRef<[u8]>>(&self, input: T) -> String {
+        let plain_bytes = input.as_ref();
+
+        let mut encoded_text = String::with_capacity(self.encoded_len(plain_bytes.len()));
+        let mut checksum = 0;
+
+        for block in plain_bytes.chunks(5) {
+            let value = (0..5).fold(0_u64, |acc, index| {
+                (acc << 8) | u64::from(block.get(index).copied().unwrap_or(0))
+            });
+
+            let symbol_count = (block.len() * 8).div_ceil(5);
+
+            for index in 0..symbol_count {
+                let digit = ((value >> (35 - 5 * index)) & 0x1f) as u8;
+
+                checksum = (checksum * 32 + u32::from(digit)) % CHECK_MODULUS;
+                encoded_text.push(char::from(self.characters[usize::from(digit)]));
+            }
+
+            if self.padding {
+                for _ in symbol_count..8 {
+                    encoded_text.push(char::from(PADDING));
+                }
+            }
+        }
+
+        if self.check_symbol {
+            encoded_text.push(char::from(check_character(&self.characters, checksum)));
+        }
+
+        encoded_text
+    }
+
+    // NOTE: The Crockford check symbol is the value of the encoded symbols modulo 37, so it covers
+    // the zero bits padding the last symbol as well.
+    pub fn decode<T: AsRef<[u8]>>(&self, input: T) -> Result<Vec<u8>, DecodeError> {
+        let mut encoded_bytes = input.as_ref();
+        let mut expected_checksum = None;
+
+        if self.check_symbol {
+            let (&ch, data_bytes) = encoded_bytes
+                .split_last()
+                .ok_or(DecodeError::InvalidLength)?;
+
+            let checksum = self
+                .check_value(ch)
+                .ok_or_else(|| DecodeError::invalid_character(encoded_bytes, data_bytes.len()))?;
+
+            expected_checksum = Some(checksum);
+            encoded_bytes = data_bytes;
+        }
+
+        let mut plain_bytes = Vec::with_capacity(encoded_bytes.len() * 5 / 8);
+
+        let mut buffer = 0_u32;
+        let mut bit_count = 0;
+        let mut symbol_count = 0;
+        let mut padding_count = 0;
+        let mut last_index = 0;
+        let mut checksum = 0;
+
+        for (index, &ch) in encoded_bytes.iter().enumerate() {
+            if self.crockford && ch == CROCKFORD_SEPARATOR {
+                continue;
+            }
+
+            if self.padding && ch == PADDING {
+                padding_count += 1;
+                continue;
+            }
+
+            if padding_count > 0 {
+                return Err(DecodeError::invalid_character(encoded_bytes, index));
+            }
+
+            let value = match self.values[usize::from(ch)] {
+                INVALID_VALUE => return Err(DecodeError::invalid_character(encoded_bytes, index)),
+                value => value,
+            };
+
+            buffer = (buffer << 5) | u32::from(value);
+            bit_count += 5;
+            symbol_count += 1;
+            last_index = index;
+            checksum = (checksum * 32 + u32::from(value)) % CHECK_MODULUS;
+
+            if bit_count >= 8 {
+                bit_count -= 8;
+                plain_bytes.push((buffer >> bit_count) as u8);
+                buffer &= (1 << bit_count) - 1;
+            }
+        }
+
+        if let 1 | 3 | 6 = symbol_count % 8 {
+            return Err(DecodeError::InvalidLength);
+        }
+
+        if (symbol_count + padding_count) % 8 != 0 && self.padding || padding_count >= 8 {
+            return Err(DecodeError::InvalidLength);
+        }
+
+        if buffer != 0 {
+            return Err(DecodeError::invalid_character(encoded_bytes, last_index));
+        }
+
+        match expected_checksum {
+            Some(expected_checksum) if expected_checksum != checksum => {
+                Err(DecodeError::InvalidChecksum)
+            }
+            _ => Ok(plain_bytes),
+        }
+    }
+
+    fn check_value(&self, ch: u8) -> Option<u32> {
+        match self.values[usize::from(ch)] {
+            INVALID_VALUE => CHECK_SYMBOLS
+                .iter()
+                .position(|&symbol| symbol == ch.to_ascii_uppercase())
+                .map(|position| (ALPHABET_LENGTH + position) as u32),
+            value => Some(u32::from(value)),
+        }
+    }
+}
+
+impl Default for Base32 {
+    fn default() -> Self {
+        Base32::STANDARD
+    }
+}
+
+fn check_character(characters: &[u8; ALPHABET_LENGTH], checksum: u32) -> u8 {
+    let checksum = checksum as usize;
+
+    match characters.get(checksum) {
+        Some(&ch) => ch,
+        None => CHECK_SYMBOLS[checksum - ALPHABET_LENGTH],
+    }
+}
+
+pub fn encode<T: AsRef<[u8]>>(input: T) -> String {
+    Base32::STANDARD.encode(input)
+}
+
+pub fn decode<T: AsRef<[u8]>>(input: T) -> Result<Vec<u8>, DecodeError> {
+    Base32::STANDARD.decode(input)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    const RFC_EXAMPLES: [(&str, &str, &str); 7] = [
+        ("", "", ""),
+        ("f", "MY======", "CO======"),
+        ("fo", "MZXQ====", "CPNG===="),
+        ("foo", "MZXW6===", "CPNMU==="),
+        ("foob", "MZXW6YQ=", "CPNMUOG="),
+        ("fooba", "MZXW6YTB", "CPNMUOJ1"),
+        ("foobar", "MZXW6YTBOI======", "CPNMUOJ1E8======"),
+    ];
+
+    #[test]
+    fn static_rfc_examples_should_work() {
+        for (plain_text, standard_text, hex_text) in RFC_EXAMPLES.iter() {
+            assert_eq!(*standard_text, encode(plain_text));
+            assert_eq!(Ok(plain_text.as_bytes().to_vec()), decode(standard_text));
+
+            assert_eq!(*hex_text, Base32::HEX.encode(plain_text));
+            assert_eq!(
+                Ok(plain_text.as_bytes().to_vec()),
+                Base32::HEX.decode(hex_text)
+            );
+
+            let unpadded_text = standard_text.trim_end_matches('=');
+
+            assert_eq!(unpadded_text, Base32::STANDARD_NO_PAD.encode(plain_text));
+            assert_eq!(
+                Ok(plain_text.as_bytes().to_vec()),
+                Base32::STANDARD_NO_PAD.decode(unpadded_text)
+            );
+            assert_eq!(
+                hex_text.trim_end_matches('='),
+                Base32::HEX_NO_PAD.encode(plain_text)
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_rfc_examples_should_fail() {
+        assert_eq!(Err(DecodeError::InvalidLength), decode("MY"));
+        assert_eq!(Err(DecodeError::InvalidLength), decode("MY====="));
+        assert_eq!(Err(DecodeError::InvalidLength), decode("M======="));
+        assert_eq!(
+            Err(DecodeError::InvalidCharacter {
+                character: 'Z',
+                index: 1
+            }),
+            decode("MZ======")
+        );
+        assert_eq!(
+            Err(DecodeError::InvalidCharacter {
+                character: 'm',
+                index: 0
+            }),
+            decode("my======")
+        );
+        assert_eq!(
+            Err(DecodeError::InvalidCharacter {
+                character: 'M',
+                index: 3
+            }),
+            decode("MY=M====")
+        );
+        assert_eq!(
+            Err(DecodeError::InvalidCharacter {
+                character: '=',
+                index: 2
+            }),
+            Base32::STANDARD_NO_PAD.decode("MY======")
+        );
+    }
+
+    #[test]
+    fn static_crockford_examples_should_work() {
+        let examples = [
+            ("", "", "0"),
+            ("f", "CR", "CR1"),
+            ("foobar", "CSQPYRK1E8", "CSQPYRK1E8R"),
+            ("hello world", "D1JPRV3F41VPYWKCCG", "D1JPRV3F41VPYWKCCGT"),
+        ];
+
+        for (plain_text, encoded_text, checked_text) in examples.iter() {
+            assert_eq!(*encoded_text, Base32::CROCKFORD.encode(plain_text));
+            assert_eq!(
+                *checked_text,
+                Base32::CROCKFORD_WITH_CHECK.encode(plain_text)
+            );
+
+            assert_eq!(
+                Ok(plain_text.as_bytes().to_vec()),
+                Base32::CROCKFORD.decode(encoded_text)
+            );
+            assert_eq!(
+                Ok(plain_text.as_bytes().to_vec()),
+                Base32::CROCKFORD_WITH_CHECK.decode(checked_text)
+            );
+        }
+    }
+
+    #[test]
+    fn crockford_should_alias_and_ignore_case() {
+        assert_eq!("ZZZG", Base32::CROCKFORD.encode([0xff, 0xff]));
+        assert_eq!("ZZZGH", Base32::CROCKFORD_WITH_CHECK.encode([0xff, 0xff]));
+
+        assert_eq!(
+            Base32::CROCKFORD.decode("D1JPRV3F41VPYWKCCG"),
+            Base32::CROCKFORD.decode("d1jprv3f41vpywkccg")
+        );
+        assert_eq!(
+            Base32::CROCKFORD.decode("D1JPRV3F41VPYWKCCG"),
+            Base32::CROCKFORD.decode("DIJPRV3F4LVPYWKCCG")
+        );
+        assert_eq!(
+            Base32::CROCKFORD.decode("D1JPRV3F41VPYWKCCG"),
+            Base32::CROCKFORD.decode("D1JPR-V3F41-VPYWK-CCG")
+        );
+        assert_eq!(Ok(vec![0, 0]), Base32::CROCKFORD.decode("oOoo"));
+        assert_eq!(
+            Err(DecodeError::InvalidCharacter {
+                character: 'U',
+                index: 1
+            }),
+            Base32::CROCKFORD.decode("CU")
+        );
+        assert_eq!(
+            Err(DecodeError::InvalidChecksum),
+            Base32::CROCKFORD_WITH_CHECK.decode("CSQPYRK1E8S")
+        );
+        assert_eq!(
+            Ok(b"foobar".to_vec()),
+            Base32::CROCKFORD_WITH_CHECK.decode("csqpyrk1e8r")
+        );
+        assert_eq!(
+            Err(DecodeError::InvalidLength),
+            Base32::CROCKFORD_WITH_CHECK.decode("")
+        );
+    }
+
+    #[test]
+    fn custom_alphabets_should_work() {
+        let z_base32 = Base32::new("ybndrfg8ejkmcpqxot1uwisza345h769", false).unwrap();
+
+        assert_eq!("pb1sa5dxrb5s6hucco", z_base32.encode("hello world"));
+        assert_eq!(
+            Err(AlphabetError::InvalidLength(3)),
+            Base32::new("abc", false)
+        );
+        assert_eq!(
+            Err(AlphabetError::DuplicateCharacter {
+                index: 31,
+                character: '='
+            }),
+            Base32::new("ABCDEFGHIJKLMNOPQRSTUVWXYZ23456=", true)
+        );
+    }
+
+    #[quickcheck]
+    fn encode_decode_should_work(bytes: Vec<u8>) -> bool {
+        [
+            Base32::STANDARD,
+            Base32::STANDARD_NO_PAD,
+            Base32::HEX,
+            Base32::HEX_NO_PAD,
+            Base32::CROCKFORD,
+            Base32::CROCKFORD_WITH_CHECK,
+        ]
+        .iter()
+        .all(|base| {
+            let encoded_text = base.encode(&bytes);
+
+            encoded_text.len() == base.encoded_len(bytes.len())
+                && base.decode(&encoded_text) == Ok(bytes.clone())
+        })
+    }
+}
@@ -0,0 +1,537 @@
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec, vec::Vec};
+
+use super::{AlphabetError, DecodeError, EncodeError};
+
+pub const MIN_RADIX: usize = 2;
+pub const MAX_RADIX: usize = 256;
+
+const INVALID_VALUE: u16 = 0xffff;
+
+const BASE58_LIMB_BASE: u64 = 58 * 58 * 58 * 58 * 58;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BaseN {
+    radix: usize,
+    characters: [u8; MAX_RADIX],
+    values: [u16; 256],
+    limb_digits: usize,
+    limb_base: u64,
+}
+
+impl BaseN {
+    pub const BASE36: BaseN = BaseN::from_characters(b"0123456789abcdefghijklmnopqrstuvwxyz");
+
+    pub const BASE58: BaseN =
+        BaseN::from_characters(b"123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz");
+
+    pub const BASE62: BaseN =
+        BaseN::from_characters(b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz");
+
+    pub fn new<T: AsRef<[u8]>>(characters: T) -> Result<Self, AlphabetError> {
+        let characters = characters.as_ref();
+
+        if characters.len() < MIN_RADIX || characters.len() > MAX_RADIX {
+            return Err(AlphabetError::InvalidLength(characters.len()));
+        }
+
+        for (index, &byte) in characters.iter().enumerate() {
+            if characters[..index].contains(&byte) {
+                return Err(AlphabetError::DuplicateCharacter {
+                    index,
+                    character: char::from(byte),
+                });
+            }
+        }
+
+        Ok(BaseN::from_characters(characters))
+    }
+
+    pub(crate) const fn from_characters(characters: &[u8]) -> Self {
+        let mut base = BaseN {
+            radix: characters.len(),
+            characters: [0; MAX_RADIX],
+            values: [INVALID_VALUE; 256],
+            limb_digits: 0,
+            limb_base: 1,
+        };
+
+        let mut index = 0;
+
+        while index < characters.len() {
+            base.characters[index] = characters[index];
+            base.values[characters[index] as usize] = index as u16;
+            index += 1;
+        }
+
+        while base.limb_base * characters.len() as u64 <= 1 << 32 {
+            base.limb_base *= characters.len() as u64;
+            base.limb_digits += 1;
+        }
+
+        base
+    }
+
+    pub fn radix(&self) -> usize {
+        self.radix
+    }
+
+    pub fn characters(&self) -> &[u8] {
+        &self.characters[..self.radix]
+    }
+
+    pub fn max_encoded_len(&self, input_length: usize) -> usize {
+        if self.radix == 58 {
+            return super::max_encoded_len(input_length);
+        }
+
+        let digit_bits = (usize::BITS - 1 - self.radix.leading_zeros()) as usize;
+
+        input_length / digit_bits * 8 + (input_length % digit_bits * 8).div_ceil(digit_bits)
+    }
+
+    pub fn max_decoded_len(&self, input_length: usize) -> usize {
+        input_length
+    }
+
+    // NOTE: A `String` only holds the encoded characters unchanged when they are all ASCII, so
+    // alphabets with other bytes have to use the slice functions instead.
+    #[cfg(feature = "alloc")]
+    pub fn encode<T: AsRef<[u8]>>(&self, input: T) -> Result<String, AlphabetError> {
+        if let Some(index) = self.characters().iter().position(|byte| !byte.is_ascii()) {
+            return Err(AlphabetError::NonAsciiCharacter {
+                index,
+                byte: self.characters[index],
+            });
+        }
+
+        let plain_bytes = input.as_ref();
+
+        let mut encoded_bytes = vec![0; self.max_encoded_len(plain_bytes.len())];
+
+        let length = match self.encode_to_slice(plain_bytes, &mut encoded_bytes) {
+            Ok(length) => length,
+            Err(EncodeError::BufferTooSmall) => {
+                unreachable!("encoding is longer than max_encoded_len")
+            }
+            Err(EncodeError::InvalidLength) => unreachable!("BaseN encodes input of any length"),
+        };
+
+        Ok(encoded_bytes[..length]
+            .iter()
+            .map(|&ch| char::from(ch))
+            .collect())
+    }
+
+    #[cfg(feature = "alloc")]
+    pub fn decode<T: AsRef<[u8]>>(&self, input: T) -> Result<Vec<u8>, DecodeError> {
+        let encoded_bytes = input.as_ref();
+
+        let mut plain_bytes = vec![0; self.max_decoded_len(encoded_bytes.len())];
+        let length = self.decode_to_slice(encoded_bytes, &mut plain_bytes)?;
+
+        plain_bytes.truncate(length);
+
+        Ok(plain_bytes)
+    }
+
+    pub fn encode_to_slice<T: AsRef<[u8]>>(
+        &self,
+        input: T,
+        output: &mut [u8],
+    ) -> Result<usize, EncodeError> {
+        let plain_bytes = input.as_ref();
+
+        let zero_count = plain_bytes.iter().take_while(|&&byte| byte == 0).count();
+
+        if output.len() < zero_count {
+            return Err(EncodeError::BufferTooSmall);
+        }
+
+        let (zero_output, value_output) = output.split_at_mut(zero_count);
+        let value_length = self.encode_value(&plain_bytes[zero_count..], value_output)?;
+
+        for ch in zero_output.iter_mut() {
+            *ch = self.character(0);
+        }
+
+        let value_output = &mut value_output[..value_length];
+        value_output.reverse();
+
+        for ch in value_output.iter_mut() {
+            *ch = self.character(*ch);
+        }
+
+        Ok(zero_count + value_length)
+    }
+
+    pub fn decode_to_slice<T: AsRef<[u8]>>(
+        &self,
+        input: T,
+        output: &mut [u8],
+    ) -> Result<usize, DecodeError> {
+        let encoded_bytes = input.as_ref();
+
+        let zero_count = encoded_bytes
+            .iter()
+            .take_while(|&&ch| ch == self.character(0))
+            .count();
+
+        if output.len() < zero_count {
+            return Err(DecodeError::BufferTooSmall);
+        }
+
+        let (zero_output, value_output) = output.split_at_mut(zero_count);
+        let value_length = self.decode_value(encoded_bytes, zero_count, value_output)?;
+
+        for byte in zero_output.iter_mut() {
+            *byte = 0;
+        }
+
+        value_output[..value_length].reverse();
+
+        Ok(zero_count + value_length)
+    }
+
+    pub fn validate<T: AsRef<[u8]>>(&self, input: T) -> Result<(), DecodeError> {
+        let encoded_bytes = input.as_ref();
+
+        match encoded_bytes
+            .iter()
+            .position(|&ch| self.value(ch).is_none())
+        {
+            Some(index) => Err(DecodeError::invalid_character(encoded_bytes, index)),
+            None => Ok(()),
+        }
+    }
+
+    pub(crate) fn character(&self, value: u8) -> u8 {
+        self.characters[usize::from(value)]
+    }
+
+    pub(crate) fn value(&self, character: u8) -> Option<u8> {
+        match self.values[usize::from(character)] {
+            INVALID_VALUE => None,
+            value => Some(value as u8),
+        }
+    }
+
+    // NOTE: Limbs are kept little-endian inside the output buffer itself, four bytes each, except
+    // the most significant one which stays in `top_limb` until a larger limb is needed. Every
+    // stored limb later expands into at least four output bytes, so the buffer never needs to be
+    // larger than the final output.
+    fn encode_value(&self, value_bytes: &[u8], output: &mut [u8]) -> Result<usize, EncodeError> {
+        // NOTE: Base58 gets its own instantiation so the limb divisions are by a constant, which
+        // the compiler turns into multiplications.
+        if self.radix == 58 {
+            encode_limbs(value_bytes, output, 58, BASE58_LIMB_BASE, 5)
+        } else {
+            encode_limbs(
+                value_bytes,
+                output,
+                self.radix as u64,
+                self.limb_base,
+                self.limb_digits,
+            )
+        }
+    }
+    fn decode_value(
+        &self,
+        encoded_bytes: &[u8],
+        zero_count: usize,
+        output: &mut [u8],
+    ) -> Result<usize, DecodeError> {
+        let radix = self.radix as u64;
+
+        let mut stored_count = 0;
+        let mut top_limb: Option<u32> = None;
+
+        let leading_length = (encoded_bytes.len() - zero_count) % self.limb_digits;
+        let mut index = zero_count;
+
+        while index < encoded_bytes.len() {
+            let group_length = if index == zero_count && leading_length > 0 {
+                leading_length
+            } else {
+                self.limb_digits
+            };
+
+            let multiplier = radix.pow(group_length as u32);
+            let mut carry = 0_u64;
+
+            for offset in index..index + group_length {
+                let value = self
+                    .value(encoded_bytes[offset])
+                    .ok_or_else(|| DecodeError::invalid_character(encoded_bytes, offset))?;
+
+                carry = carry * radix + u64::from(value);
+            }
+
+            index += group_length;
+
+            for limb_bytes in output[..4 * stored_count].chunks_exact_mut(4) {
+                carry += u64::from(read_limb(limb_bytes)) * multiplier;
+                write_limb(limb_bytes, carry as u32);
+                carry >>= 32;
+            }
+
+            if let Some(limb) = top_limb {
+                carry += u64::from(limb) * multiplier;
+                top_limb = Some(carry as u32);
+                carry >>= 32;
+            }
+
+            while carry > 0 {
+                if let Some(limb) = top_limb {
+                    store_limb(output, stored_count, limb).ok_or(DecodeError::BufferTooSmall)?;
+                    stored_count += 1;
+                }
+
+                top_limb = Some(carry as u32);
+                carry >>= 32;
+            }
+        }
+
+        let mut top_limb = match top_limb {
+            Some(limb) => limb,
+            None => return Ok(0),
+        };
+
+        let mut length = 4 * stored_count;
+
+        while top_limb > 0 {
+            *output.get_mut(length).ok_or(DecodeError::BufferTooSmall)? = top_limb as u8;
+            top_limb >>= 8;
+            length += 1;
+        }
+
+        Ok(length)
+    }
+}
+
+impl Default for BaseN {
+    fn default() -> Self {
+        BaseN::BASE58
+    }
+}
+
+#[inline(always)]
+fn encode_limbs(
+    value_bytes: &[u8],
+    output: &mut [u8],
+    radix: u64,
+    limb_base: u64,
+    limb_digits: usize,
+) -> Result<usize, EncodeError> {
+    let mut stored_count = 0;
+    let mut top_limb: Option<u32> = None;
+
+    let leading_length = value_bytes.len() % 4;
+    let (leading_bytes, word_bytes) = value_bytes.split_at(leading_length);
+
+    for word in Some(leading_bytes)
+        .filter(|bytes| !bytes.is_empty())
+        .into_iter()
+        .chain(word_bytes.chunks_exact(4))
+    {
+        let shift = 8 * word.len() as u32;
+        let mut carry = word
+            .iter()
+            .fold(0_u64, |acc, &byte| (acc << 8) + u64::from(byte));
+
+        for limb_bytes in output[..4 * stored_count].chunks_exact_mut(4) {
+            carry += u64::from(read_limb(limb_bytes)) << shift;
+            write_limb(limb_bytes, (carry % limb_base) as u32);
+            carry /= limb_base;
+        }
+
+        if let Some(limb) = top_limb {
+            carry += u64::from(limb) << shift;
+            top_limb = Some((carry % limb_base) as u32);
+            carry /= limb_base;
+        }
+
+        while carry > 0 {
+            if let Some(limb) = top_limb {
+                store_limb(output, stored_count, limb).ok_or(EncodeError::BufferTooSmall)?;
+                stored_count += 1;
+            }
+
+            top_limb = Some((carry % limb_base) as u32);
+            carry /= limb_base;
+        }
+    }
+
+    let mut top_limb = match top_limb {
+        Some(limb) => u64::from(limb),
+        None => return Ok(0),
+    };
+
+    let mut length = limb_digits * stored_count;
+
+    while top_limb > 0 {
+        *output.get_mut(length).ok_or(EncodeError::BufferTooSmall)? = (top_limb % radix) as u8;
+        top_limb /= radix;
+        length += 1;
+    }
+
+    for index in (0..stored_count).rev() {
+        let mut limb = u64::from(read_limb(&output[4 * index..4 * index + 4]));
+
+        let digits_index = limb_digits * index;
+
+        for coefficient in output[digits_index..digits_index + limb_digits].iter_mut() {
+            *coefficient = (limb % radix) as u8;
+            limb /= radix;
+        }
+    }
+
+    Ok(length)
+}
+
+fn read_limb(limb_bytes: &[u8]) -> u32 {
+    u32::from_le_bytes([limb_bytes[0], limb_bytes[1], limb_bytes[2], limb_bytes[3]])
+}
+
+fn write_limb(limb_bytes: &mut [u8], limb: u32) {
+    limb_bytes.copy_from_slice(&limb.to_le_bytes());
+}
+
+fn store_limb(output: &mut [u8], index: usize, limb: u32) -> Option<()> {
+    let limb_bytes = output.get_mut(4 * index..4 * index + 4)?;
+
+    write_limb(limb_bytes, limb);
+
+    Some(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use super::super::Alphabet;
+
+    #[test]
+    fn static_examples_should_work() {
+        assert_eq!(
+            Ok(String::from("fuvrsivvnfrbjwajo")),
+            BaseN::BASE36.encode("hello world")
+        );
+        assert_eq!(
+            Ok(String::from("003ssir")),
+            BaseN::BASE36.encode(b"\0\0abc")
+        );
+        assert_eq!(Ok(String::from("73")), BaseN::BASE36.encode([0xff]));
+
+        assert_eq!(
+            Ok(String::from("AAwf93rvy4aWQVw")),
+            BaseN::BASE62.encode("hello world")
+        );
+        assert_eq!(Ok(String::from("00QmIN")), BaseN::BASE62.encode(b"\0\0abc"));
+        assert_eq!(Ok(String::from("47")), BaseN::BASE62.encode([0xff]));
+
+        assert_eq!(
+            Ok(String::from("StV1DL6CwTryKyV")),
+            BaseN::BASE58.encode("hello world")
+        );
+
+        assert_eq!(
+            Ok(b"hello world".to_vec()),
+            BaseN::BASE36.decode("fuvrsivvnfrbjwajo")
+        );
+        assert_eq!(Ok(b"\0\0abc".to_vec()), BaseN::BASE62.decode("00QmIN"));
+        assert_eq!(
+            Err(DecodeError::InvalidCharacter {
+                character: 'A',
+                index: 2
+            }),
+            BaseN::BASE36.decode("fuA")
+        );
+    }
+
+    #[test]
+    fn small_and_large_radixes_should_work() {
+        let binary = BaseN::new("01").unwrap();
+
+        assert_eq!(2, binary.radix());
+        assert_eq!(
+            Ok(String::from("010110000000")),
+            binary.encode([0, 0x05, 0x80])
+        );
+        assert_eq!(Ok(vec![0, 0x05, 0x80]), binary.decode("010110000000"));
+
+        let all_bytes: Vec<u8> = (0..=255).collect();
+        let identity = BaseN::new(&all_bytes).unwrap();
+        let mut output = [0; 8];
+
+        assert_eq!(256, identity.radix());
+        assert_eq!(Ok(5), identity.encode_to_slice(b"\0\x01abc", &mut output));
+        assert_eq!(b"\0\x01abc", &output[..5]);
+        assert_eq!(Ok(b"\0\x01abc".to_vec()), identity.decode(&output[..5]));
+        assert_eq!(
+            Err(AlphabetError::NonAsciiCharacter {
+                index: 128,
+                byte: 0x80
+            }),
+            identity.encode(b"\0\x01abc")
+        );
+    }
+
+    #[test]
+    fn invalid_alphabets_should_fail() {
+        assert_eq!(Err(AlphabetError::InvalidLength(1)), BaseN::new("0"));
+        assert_eq!(
+            Err(AlphabetError::InvalidLength(257)),
+            BaseN::new(vec![0; 257])
+        );
+        assert_eq!(
+            Err(AlphabetError::DuplicateCharacter {
+                index: 2,
+                character: 'a'
+            }),
+            BaseN::new("abac")
+        );
+    }
+
+    #[test]
+    fn base58_should_share_the_engine() {
+        assert_eq!(BaseN::BASE58, *Alphabet::BITCOIN.base());
+        assert_eq!(BaseN::BASE58, BaseN::from(Alphabet::BITCOIN));
+        assert_eq!(BaseN::default(), BaseN::BASE58);
+    }
+
+    #[quickcheck]
+    fn encode_decode_should_work(bytes: Vec<u8>, radix: u8) -> bool {
+        let characters: Vec<u8> = (0..=255).collect();
+        let base = BaseN::new(&characters[..(usize::from(radix) + 1).max(2)]).unwrap();
+
+        let mut output = vec![0; base.max_encoded_len(bytes.len())];
+        let length = base.encode_to_slice(&bytes, &mut output).unwrap();
+
+        let encoded_text = if base.radix() <= 128 {
+            Ok(output[..length].to_vec())
+        } else {
+            Err(AlphabetError::NonAsciiCharacter {
+                index: 128,
+                byte: 0x80,
+            })
+        };
+
+        base.encode(&bytes).map(String::into_bytes) == encoded_text
+            && base.decode(&output[..length]) == Ok(bytes)
+    }
+
+    #[quickcheck]
+    fn validate_should_match_decode(text: String) -> bool {
+        [BaseN::BASE36, BaseN::BASE58, BaseN::BASE62]
+            .iter()
+            .all(|base| base.validate(&text) == base.decode(&text).map(|_| ()))
+    }
+
+    #[quickcheck]
+    fn presets_should_match_base58_engine(bytes: Vec<u8>) -> bool {
+        BaseN::BASE58.encode(&bytes) == Ok(super::super::encode(&bytes))
+            && BaseN::BASE36.decode(BaseN::BASE36.encode(&bytes).unwrap()) == Ok(bytes.clone())
+            && BaseN::BASE62.decode(BaseN::BASE62.encode(&bytes).unwrap()) == Ok(bytes)
+    }
+}
@@ -0,0 +1,756 @@
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+#[cfg(feature = "std")]
+use std::error::Error;
+
+pub const MAX_LENGTH: usize = 90;
+pub const CHECKSUM_LENGTH: usize = 6;
+
+const CHARACTERS: [u8; 32] = *b"qpzry9x8gf2tvdw0s3jn54khce6mua7l";
+const SEPARATOR: char = '1';
+const GENERATORS: [u32; 5] = [0x3b6a57b2, 0x26508e6d, 0x1ea119fa, 0x3d4233dd, 0x2a1462b3];
+
+const MAX_WITNESS_VERSION: u8 = 16;
+const MIN_PROGRAM_LENGTH: usize = 2;
+const MAX_PROGRAM_LENGTH: usize = 40;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Variant {
+    Bech32,
+    Bech32m,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Bech32Error {
+    InvalidBitWidth(u32),
+    InvalidCharacter { character: char, index: usize },
+    InvalidChecksum { error_index: Option<usize> },
+    InvalidHrp,
+    InvalidLength(usize),
+    InvalidPadding,
+    InvalidProgramLength(usize),
+    InvalidValue { value: u8, index: usize },
+    InvalidVariant(Variant),
+    InvalidWitnessVersion(u8),
+    MissingSeparator,
+    MixedCase { index: usize },
+}
+
+impl Variant {
+    fn constant(self) -> u32 {
+        match self {
+            Variant::Bech32 => 1,
+            Variant::Bech32m => 0x2bc830a3,
+        }
+    }
+
+    fn from_residue(residue: u32) -> Option<Variant> {
+        [Variant::Bech32, Variant::Bech32m]
+            .iter()
+            .copied()
+            .find(|variant| variant.constant() == residue)
+    }
+}
+
+pub fn encode<T: AsRef<[u8]>>(hrp: &str, data: T, variant: Variant) -> Result<String, Bech32Error> {
+    let data = data.as_ref();
+
+    let hrp_bytes = hrp.as_bytes();
+
+    if hrp_bytes.is_empty() {
+        return Err(Bech32Error::InvalidHrp);
+    }
+
+    check_characters(hrp)?;
+
+    let length = hrp_bytes.len() + 1 + data.len() + CHECKSUM_LENGTH;
+
+    if length > MAX_LENGTH {
+        return Err(Bech32Error::InvalidLength(length));
+    }
+
+    if let Some(index) = data.iter().position(|&value| value >= 32) {
+        return Err(Bech32Error::InvalidValue {
+            value: data[index],
+            index,
+        });
+    }
+
+    let hrp = hrp.to_ascii_lowercase();
+    let residue = polymod(
+        hrp.as_bytes(),
+        data.iter().copied().chain([0; CHECKSUM_LENGTH]),
+    ) ^ variant.constant();
+
+    let mut encoded_text = String::with_capacity(length);
+    encoded_text.push_str(&hrp);
+    encoded_text.push(SEPARATOR);
+
+    for &value in data {
+        encoded_text.push(char::from(CHARACTERS[usize::from(value)]));
+    }
+
+    for index in 0..CHECKSUM_LENGTH {
+        let value = (residue >> (5 * (CHECKSUM_LENGTH - 1 - index))) & 0x1f;
+
+        encoded_text.push(char::from(CHARACTERS[value as usize]));
+    }
+
+    Ok(encoded_text)
+}
+
+pub fn decode(input: &str) -> Result<(String, Vec<u8>, Variant), Bech32Error> {
+    let encoded_bytes = input.as_bytes();
+
+    check_characters(input)?;
+
+    if encoded_bytes.len() > MAX_LENGTH {
+        return Err(Bech32Error::InvalidLength(encoded_bytes.len()));
+    }
+
+    let separator_index = input
+        .rfind(SEPARATOR)
+        .ok_or(Bech32Error::MissingSeparator)?;
+
+    if separator_index == 0 {
+        return Err(Bech32Error::InvalidHrp);
+    }
+
+    if encoded_bytes.len() - separator_index - 1 < CHECKSUM_LENGTH {
+        return Err(Bech32Error::InvalidLength(encoded_bytes.len()));
+    }
+
+    let hrp = input[..separator_index].to_ascii_lowercase();
+    let data_index = separator_index + 1;
+
+    let mut data = Vec::with_capacity(encoded_bytes.len() - data_index);
+
+    for (index, &ch) in encoded_bytes.iter().enumerate().skip(data_index) {
+        let value = CHARACTERS
+            .iter()
+            .position(|&candidate| candidate == ch.to_ascii_lowercase())
+            .ok_or_else(|| invalid_character(input, index))?;
+
+        data.push(value as u8);
+    }
+
+    let residue = polymod(hrp.as_bytes(), data.iter().copied());
+
+    let variant = Variant::from_residue(residue).ok_or_else(|| Bech32Error::InvalidChecksum {
+        error_index: locate_error(hrp.as_bytes(), &mut data).map(|index| data_index + index),
+    })?;
+
+    data.truncate(data.len() - CHECKSUM_LENGTH);
+
+    Ok((hrp, data, variant))
+}
+
+pub fn convert_bits<T: AsRef<[u8]>>(
+    input: T,
+    from_bits: u32,
+    to_bits: u32,
+    pad: bool,
+) -> Result<Vec<u8>, Bech32Error> {
+    let input = input.as_ref();
+
+    for &bits in [from_bits, to_bits].iter() {
+        if !(1..=8).contains(&bits) {
+            return Err(Bech32Error::InvalidBitWidth(bits));
+        }
+    }
+
+    let max_value = (1_u32 << to_bits) - 1;
+    let mut output = Vec::with_capacity(input.len() * from_bits as usize / to_bits as usize + 1);
+
+    let mut buffer = 0_u32;
+    let mut bit_count = 0;
+
+    for (index, &value) in input.iter().enumerate() {
+        if u32::from(value) >> from_bits != 0 {
+            return Err(Bech32Error::InvalidValue { value, index });
+        }
+
+        buffer = (buffer << from_bits) | u32::from(value);
+        bit_count += from_bits;
+
+        while bit_count >= to_bits {
+            bit_count -= to_bits;
+            output.push(((buffer >> bit_count) & max_value) as u8);
+        }
+
+        buffer &= (1 << bit_count) - 1;
+    }
+
+    if pad {
+        if bit_count > 0 {
+            output.push(((buffer << (to_bits - bit_count)) & max_value) as u8);
+        }
+    } else if bit_count >= from_bits || buffer != 0 {
+        return Err(Bech32Error::InvalidPadding);
+    }
+
+    Ok(output)
+}
+
+pub fn encode_segwit<T: AsRef<[u8]>>(
+    hrp: &str,
+    witness_version: u8,
+    program: T,
+) -> Result<String, Bech32Error> {
+    let program = program.as_ref();
+
+    check_witness_program(witness_version, program)?;
+
+    let mut data = Vec::with_capacity(1 + (program.len() * 8).div_ceil(5));
+    data.push(witness_version);
+    data.extend(convert_bits(program, 8, 5, true)?);
+
+    encode(hrp, data, witness_variant(witness_version))
+}
+
+pub fn decode_segwit(hrp: &str, input: &str) -> Result<(u8, Vec<u8>), Bech32Error> {
+    let (actual_hrp, data, variant) = decode(input)?;
+
+    if !actual_hrp.eq_ignore_ascii_case(hrp) {
+        return Err(Bech32Error::InvalidHrp);
+    }
+
+    let (&witness_version, program_data) = data
+        .split_first()
+        .ok_or(Bech32Error::InvalidLength(input.len()))?;
+
+    if witness_version > MAX_WITNESS_VERSION {
+        return Err(Bech32Error::InvalidWitnessVersion(witness_version));
+    }
+
+    let program = convert_bits(program_data, 5, 8, false)?;
+
+    check_witness_program(witness_version, &program)?;
+
+    if variant != witness_variant(witness_version) {
+        return Err(Bech32Error::InvalidVariant(variant));
+    }
+
+    Ok((witness_version, program))
+}
+
+fn witness_variant(witness_version: u8) -> Variant {
+    if witness_version == 0 {
+        Variant::Bech32
+    } else {
+        Variant::Bech32m
+    }
+}
+
+fn check_witness_program(witness_version: u8, program: &[u8]) -> Result<(), Bech32Error> {
+    if witness_version > MAX_WITNESS_VERSION {
+        return Err(Bech32Error::InvalidWitnessVersion(witness_version));
+    }
+
+    let valid_length = if witness_version == 0 {
+        program.len() == 20 || program.len() == 32
+    } else {
+        (MIN_PROGRAM_LENGTH..=MAX_PROGRAM_LENGTH).contains(&program.len())
+    };
+
+    if !valid_length {
+        return Err(Bech32Error::InvalidProgramLength(program.len()));
+    }
+
+    Ok(())
+}
+
+fn check_characters(text: &str) -> Result<(), Bech32Error> {
+    let mut first_case = None;
+
+    for (index, &ch) in text.as_bytes().iter().enumerate() {
+        if !(33..=126).contains(&ch) {
+            return Err(invalid_character(text, index));
+        }
+
+        if ch.is_ascii_alphabetic() {
+            match first_case {
+                None => first_case = Some(ch.is_ascii_uppercase()),
+                Some(uppercase) if uppercase != ch.is_ascii_uppercase() => {
+                    return Err(Bech32Error::MixedCase { index })
+                }
+                Some(_) => {}
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn invalid_character(text: &str, index: usize) -> Bech32Error {
+    Bech32Error::InvalidCharacter {
+        character: text
+            .get(index..)
+            .and_then(|remaining_text| remaining_text.chars().next())
+            .unwrap_or(char::REPLACEMENT_CHARACTER),
+        index,
+    }
+}
+
+fn polymod<I: Iterator<Item = u8>>(hrp: &[u8], data: I) -> u32 {
+    let hrp_values = hrp
+        .iter()
+        .map(|&ch| ch >> 5)
+        .chain(Some(0))
+        .chain(hrp.iter().map(|&ch| ch & 0x1f));
+
+    hrp_values.chain(data).fold(1, |checksum, value| {
+        let top = checksum >> 25;
+
+        GENERATORS
+            .iter()
+            .enumerate()
+            .filter(|(bit, _)| (top >> bit) & 1 == 1)
+            .fold(
+                ((checksum & 0x1ffffff) << 5) ^ u32::from(value),
+                |checksum, (_, generator)| checksum ^ generator,
+            )
+    })
+}
+
+// NOTE: Both checksums correct any single substitution, so trying every other value at every
+// position finds the character to fix when there is only one wrong.
+fn locate_error(hrp: &[u8], data: &mut [u8]) -> Option<usize> {
+    for index in 0..data.len() {
+        let original = data[index];
+
+        for value in (0..32).filter(|&value| value != original) {
+            data[index] = value;
+
+            if Variant::from_residue(polymod(hrp, data.iter().copied())).is_some() {
+                data[index] = original;
+                return Some(index);
+            }
+        }
+
+        data[index] = original;
+    }
+
+    None
+}
+
+impl fmt::Display for Variant {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Variant::Bech32 => write!(f, "Bech32"),
+            Variant::Bech32m => write!(f, "Bech32m"),
+        }
+    }
+}
+
+impl fmt::Display for Bech32Error {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Bech32Error::InvalidBitWidth(bits) => write!(f, "invalid bit width {}", bits),
+            Bech32Error::InvalidCharacter { character, index } => {
+                write!(f, "invalid character {:?} at byte {}", character, index)
+            }
+            Bech32Error::InvalidChecksum {
+                error_index: Some(index),
+            } => write!(f, "invalid checksum, likely error at byte {}", index),
+            Bech32Error::InvalidChecksum { error_index: None } => write!(f, "invalid checksum"),
+            Bech32Error::InvalidHrp => write!(f, "invalid human-readable part"),
+            Bech32Error::InvalidLength(length) => write!(f, "invalid length {}", length),
+            Bech32Error::InvalidPadding => write!(f, "invalid padding"),
+            Bech32Error::InvalidProgramLength(length) => {
+                write!(f, "invalid witness program length {}", length)
+            }
+            Bech32Error::InvalidValue { value, index } => {
+                write!(f, "invalid value {} at index {}", value, index)
+            }
+            Bech32Error::InvalidVariant(variant) => {
+                write!(
+                    f,
+                    "unexpected {} checksum for this witness version",
+                    variant
+                )
+            }
+            Bech32Error::InvalidWitnessVersion(version) => {
+                write!(f, "invalid witness version {}", version)
+            }
+            Bech32Error::MissingSeparator => write!(f, "missing separator '1'"),
+            Bech32Error::MixedCase { index } => write!(f, "mixed case at byte {}", index),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for Bech32Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{string::ToString, vec};
+
+    #[test]
+    fn static_checksum_examples_should_work() {
+        let examples = [
+            ("A12UEL5L", Variant::Bech32),
+            ("a12uel5l", Variant::Bech32),
+            (
+                "an83characterlonghumanreadablepartthatcontainsthenumber1andtheexcludedcharactersbio1tt5tgs",
+                Variant::Bech32,
+            ),
+            ("abcdef1qpzry9x8gf2tvdw0s3jn54khce6mua7lmqqqxw", Variant::Bech32),
+            ("split1checkupstagehandshakeupstreamerranterredcaperred2y9e3w", Variant::Bech32),
+            ("?1ezyfcl", Variant::Bech32),
+            ("A1LQFN3A", Variant::Bech32m),
+            ("a1lqfn3a", Variant::Bech32m),
+            (
+                "an83characterlonghumanreadablepartthatcontainsthetheexcludedcharactersbioandnumber11sg7hg6",
+                Variant::Bech32m,
+            ),
+            ("abcdef1l7aum6echk45nj3s0wdvt2fg8x9yrzpqzd3ryx", Variant::Bech32m),
+            ("split1checkupstagehandshakeupstreamerranterredcaperredlc445v", Variant::Bech32m),
+            ("?1v759aa", Variant::Bech32m),
+        ];
+
+        for (encoded_text, variant) in examples.iter() {
+            let (hrp, data, actual_variant) = decode(encoded_text).unwrap();
+
+            assert_eq!(*variant, actual_variant);
+            assert_eq!(
+                Ok(encoded_text.to_ascii_lowercase()),
+                encode(&hrp, data, *variant)
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_checksum_examples_should_fail() {
+        let examples = [
+            (
+                "\u{20}1nwldj5",
+                Bech32Error::InvalidCharacter {
+                    character: ' ',
+                    index: 0,
+                },
+            ),
+            (
+                "\u{7f}1axkwrx",
+                Bech32Error::InvalidCharacter {
+                    character: '\u{7f}',
+                    index: 0,
+                },
+            ),
+            (
+                "\u{80}1eym55h",
+                Bech32Error::InvalidCharacter {
+                    character: '\u{80}',
+                    index: 0,
+                },
+            ),
+            (
+                "an84characterslonghumanreadablepartthatcontainsthenumber1andtheexcludedcharactersbio1569pvx",
+                Bech32Error::InvalidLength(91),
+            ),
+            ("pzry9x0s0muk", Bech32Error::MissingSeparator),
+            ("1pzry9x0s0muk", Bech32Error::InvalidHrp),
+            (
+                "x1b4n0q5v",
+                Bech32Error::InvalidCharacter {
+                    character: 'b',
+                    index: 2,
+                },
+            ),
+            ("li1dgmt3", Bech32Error::InvalidLength(8)),
+            (
+                "de1lg7wt\u{ff}",
+                Bech32Error::InvalidCharacter {
+                    character: '\u{ff}',
+                    index: 8,
+                },
+            ),
+            ("10a06t8", Bech32Error::InvalidHrp),
+            ("1qzzfhee", Bech32Error::InvalidHrp),
+            (
+                "y1b0jsk6g",
+                Bech32Error::InvalidCharacter {
+                    character: 'b',
+                    index: 2,
+                },
+            ),
+            (
+                "lt1igcx5c0",
+                Bech32Error::InvalidCharacter {
+                    character: 'i',
+                    index: 3,
+                },
+            ),
+            ("in1muywd", Bech32Error::InvalidLength(8)),
+            (
+                "mm1crxm3i",
+                Bech32Error::InvalidCharacter {
+                    character: 'i',
+                    index: 8,
+                },
+            ),
+            (
+                "au1s5cgom",
+                Bech32Error::InvalidCharacter {
+                    character: 'o',
+                    index: 7,
+                },
+            ),
+        ];
+
+        for (encoded_text, error) in examples.iter() {
+            assert_eq!(Err(*error), decode(encoded_text), "{:?}", encoded_text);
+        }
+
+        assert!(matches!(
+            decode("A1G7SGD8"),
+            Err(Bech32Error::InvalidChecksum { .. })
+        ));
+        assert!(matches!(
+            decode("M1VUXWEZ"),
+            Err(Bech32Error::InvalidChecksum { .. })
+        ));
+    }
+
+    #[test]
+    fn static_segwit_examples_should_work() {
+        let examples = [
+            (
+                "bc",
+                "BC1QW508D6QEJXTDG4Y5R3ZARVARY0C5XW7KV8F3T4",
+                0,
+                "751e76e8199196d454941c45d1b3a323f1433bd6",
+            ),
+            (
+                "tb",
+                "tb1qrp33g0q5c5txsp9arysrx4k6zdkfs4nce4xj0gdcccefvpysxf3q0sl5k7",
+                0,
+                "1863143c14c5166804bd19203356da136c985678cd4d27a1b8c6329604903262",
+            ),
+            (
+                "bc",
+                "bc1pw508d6qejxtdg4y5r3zarvary0c5xw7kw508d6qejxtdg4y5r3zarvary0c5xw7kt5nd6y",
+                1,
+                "751e76e8199196d454941c45d1b3a323f1433bd6751e76e8199196d454941c45d1b3a323f1433bd6",
+            ),
+            ("bc", "BC1SW50QGDZ25J", 16, "751e"),
+            (
+                "bc",
+                "bc1zw508d6qejxtdg4y5r3zarvaryvaxxpcs",
+                2,
+                "751e76e8199196d454941c45d1b3a323",
+            ),
+            (
+                "tb",
+                "tb1qqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesrxh6hy",
+                0,
+                "000000c4a5cad46221b2a187905e5266362b99d5e91c6ce24d165dab93e86433",
+            ),
+            (
+                "tb",
+                "tb1pqqqqp399et2xygdj5xreqhjjvcmzhxw4aywxecjdzew6hylgvsesf3hn0c",
+                1,
+                "000000c4a5cad46221b2a187905e5266362b99d5e91c6ce24d165dab93e86433",
+            ),
+            (
+                "bc",
+                "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqzk5jj0",
+                1,
+                "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+            ),
+        ];
+
+        for (hrp, address, witness_version, program_hex) in examples.iter() {
+            let program = hex(program_hex);
+
+            assert_eq!(
+                Ok((*witness_version, program.clone())),
+                decode_segwit(hrp, address)
+            );
+            assert_eq!(
+                Ok(address.to_ascii_lowercase()),
+                encode_segwit(hrp, *witness_version, &program)
+            );
+        }
+    }
+
+    #[test]
+    fn invalid_segwit_examples_should_fail() {
+        let examples = [
+            (
+                "bc",
+                "tc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vq5zuyut",
+                Bech32Error::InvalidHrp,
+            ),
+            (
+                "bc",
+                "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqh2y7hd",
+                Bech32Error::InvalidVariant(Variant::Bech32),
+            ),
+            (
+                "tb",
+                "tb1z0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vqglt7rf",
+                Bech32Error::InvalidVariant(Variant::Bech32),
+            ),
+            (
+                "bc",
+                "BC1S0XLXVLHEMJA6C4DQV22UAPCTQUPFHLXM9H8Z3K2E72Q4K9HCZ7VQ54WELL",
+                Bech32Error::InvalidVariant(Variant::Bech32),
+            ),
+            (
+                "bc",
+                "bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kemeawh",
+                Bech32Error::InvalidVariant(Variant::Bech32m),
+            ),
+            (
+                "tb",
+                "tb1q0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vq24jc47",
+                Bech32Error::InvalidVariant(Variant::Bech32m),
+            ),
+            (
+                "bc",
+                "bc1p38j9r5y49hruaue7wxjce0updqjuyyx0kh56v8s25huc6995vvpql3jow4",
+                Bech32Error::InvalidCharacter {
+                    character: 'o',
+                    index: 59,
+                },
+            ),
+            (
+                "bc",
+                "BC130XLXVLHEMJA6C4DQV22UAPCTQUPFHLXM9H8Z3K2E72Q4K9HCZ7VQ7ZWS8R",
+                Bech32Error::InvalidWitnessVersion(17),
+            ),
+            ("bc", "bc1pw5dgrnzv", Bech32Error::InvalidProgramLength(1)),
+            (
+                "bc",
+                "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7v8n0nx0muaewav253zgeav",
+                Bech32Error::InvalidProgramLength(41),
+            ),
+            (
+                "bc",
+                "BC1QR508D6QEJXTDG4Y5R3ZARVARYV98GJ9P",
+                Bech32Error::InvalidProgramLength(16),
+            ),
+            (
+                "tb",
+                "tb1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vq47Zagq",
+                Bech32Error::MixedCase { index: 58 },
+            ),
+            (
+                "bc",
+                "bc1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7v07qwwzcrf",
+                Bech32Error::InvalidPadding,
+            ),
+            (
+                "tb",
+                "tb1p0xlxvlhemja6c4dqv22uapctqupfhlxm9h8z3k2e72q4k9hcz7vpggkg4j",
+                Bech32Error::InvalidPadding,
+            ),
+            ("bc", "bc1gmk9yu", Bech32Error::InvalidLength(9)),
+        ];
+
+        for (hrp, address, error) in examples.iter() {
+            assert_eq!(Err(*error), decode_segwit(hrp, address), "{}", address);
+        }
+    }
+
+    #[test]
+    fn checksum_errors_should_be_located() {
+        assert_eq!(
+            Err(Bech32Error::InvalidChecksum {
+                error_index: Some(9)
+            }),
+            decode("bc1qw508d7qejxtdg4y5r3zarvary0c5xw7kv8f3t4")
+        );
+        assert_eq!(
+            Err(Bech32Error::InvalidChecksum {
+                error_index: Some(41)
+            }),
+            decode("bc1qw508d6qejxtdg4y5r3zarvary0c5xw7kv8f3t5")
+        );
+        assert_eq!(
+            "invalid checksum, likely error at byte 9",
+            decode("bc1qw508d7qejxtdg4y5r3zarvary0c5xw7kv8f3t4")
+                .unwrap_err()
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn invalid_bit_widths_should_fail() {
+        assert_eq!(
+            Err(Bech32Error::InvalidBitWidth(0)),
+            convert_bits([0], 0, 5, true)
+        );
+        assert_eq!(
+            Err(Bech32Error::InvalidBitWidth(32)),
+            convert_bits([0], 8, 32, true)
+        );
+        assert_eq!(
+            Err(Bech32Error::InvalidBitWidth(9)),
+            convert_bits([0], 9, 5, false)
+        );
+        assert_eq!(
+            Ok(vec![1, 0, 1, 0, 0, 0, 0, 0]),
+            convert_bits([0xa0], 8, 1, false)
+        );
+    }
+
+    #[test]
+    fn invalid_encodings_should_fail() {
+        assert_eq!(
+            Err(Bech32Error::InvalidHrp),
+            encode("", [], Variant::Bech32)
+        );
+        assert_eq!(
+            Err(Bech32Error::InvalidValue {
+                value: 32,
+                index: 1
+            }),
+            encode("bc", [0, 32], Variant::Bech32)
+        );
+        assert_eq!(
+            Err(Bech32Error::MixedCase { index: 1 }),
+            encode("Bc", [], Variant::Bech32)
+        );
+        assert_eq!(
+            Err(Bech32Error::InvalidLength(91)),
+            encode("bc", [0; 82], Variant::Bech32m)
+        );
+        assert_eq!(
+            Err(Bech32Error::InvalidProgramLength(21)),
+            encode_segwit("bc", 0, [0; 21])
+        );
+        assert_eq!(
+            Err(Bech32Error::InvalidWitnessVersion(17)),
+            encode_segwit("bc", 17, [0; 20])
+        );
+    }
+
+    #[quickcheck]
+    fn encode_decode_should_work(bytes: Vec<u8>, bech32m: bool) -> bool {
+        let variant = if bech32m {
+            Variant::Bech32m
+        } else {
+            Variant::Bech32
+        };
+        let data = convert_bits(&bytes[..bytes.len().min(40)], 8, 5, true).unwrap();
+
+        decode(&encode("test", &data, variant).unwrap())
+            == Ok((String::from("test"), data, variant))
+    }
+
+    #[quickcheck]
+    fn convert_bits_should_round_trip(bytes: Vec<u8>) -> bool {
+        convert_bits(&bytes, 8, 5, true).and_then(|data| convert_bits(data, 5, 8, false))
+            == Ok(bytes)
+    }
+
+    fn hex(text: &str) -> Vec<u8> {
+        (0..text.len())
+            .step_by(2)
+            .map(|index| u8::from_str_radix(&text[index..index + 2], 16).unwrap())
+            .collect()
+    }
+}
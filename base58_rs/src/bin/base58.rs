@@ -0,0 +1,356 @@
+extern crate base58_rs;
+
+use std::env;
+use std::fmt;
+use std::fs::File;
+use std::io::{self, Read, Write};
+use std::process;
+
+use base58_rs::{check, Alphabet, DecodeError};
+
+const USAGE: &str = "\
+Usage: base58 [OPTIONS] [FILE]...
+
+Encode or decode each FILE (or standard input when no FILE or - is given).
+
+Options:
+  -d, --decode             decode instead of encode
+  -a, --alphabet NAME      bitcoin (default), ripple, flickr or 58 custom characters
+  -c, --check              use Base58Check; the first byte of the payload is the version
+  -x, --hex                read hex when encoding, write hex when decoding
+  -t, --trim               strip trailing newlines from the input before encoding
+  -n, --no-newline         do not print a newline after each text output
+  -h, --help               print this help";
+
+#[derive(Debug, PartialEq)]
+struct Options {
+    decode: bool,
+    alphabet: Alphabet,
+    check: bool,
+    hex: bool,
+    trim: bool,
+    newline: bool,
+    paths: Vec<String>,
+}
+
+#[derive(Debug, PartialEq)]
+enum CliError {
+    Usage(String),
+    Io(String),
+    InvalidHex { index: usize },
+    MissingVersion,
+    Decode(DecodeError),
+}
+
+impl fmt::Display for CliError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            CliError::Usage(message) => write!(f, "{}", message),
+            CliError::Io(message) => write!(f, "{}", message),
+            CliError::InvalidHex { index } => write!(f, "invalid hex digit at byte {}", index),
+            CliError::MissingVersion => write!(f, "Base58Check input needs a version byte"),
+            CliError::Decode(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+fn main() {
+    let options = match parse_options(env::args().skip(1)) {
+        Ok(Some(options)) => options,
+        Ok(None) => {
+            println!("{}", USAGE);
+            return;
+        }
+        Err(error) => {
+            eprintln!("base58: {}\n\n{}", error, USAGE);
+            process::exit(2);
+        }
+    };
+
+    let stdout = io::stdout();
+    let mut output = stdout.lock();
+
+    for path in &options.paths {
+        let result = read_input(path)
+            .and_then(|input| run(&options, &input))
+            .and_then(|bytes| {
+                output
+                    .write_all(&bytes)
+                    .map_err(|error| CliError::Io(error.to_string()))
+            });
+
+        if let Err(error) = result {
+            let name = if path == "-" { "stdin" } else { path };
+
+            eprintln!("base58: {}: {}", name, error);
+            process::exit(1);
+        }
+    }
+}
+
+fn parse_options<I: Iterator<Item = String>>(mut args: I) -> Result<Option<Options>, CliError> {
+    let mut options = Options {
+        decode: false,
+        alphabet: Alphabet::BITCOIN,
+        check: false,
+        hex: false,
+        trim: false,
+        newline: true,
+        paths: vec![],
+    };
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-d" | "--decode" => options.decode = true,
+            "-c" | "--check" => options.check = true,
+            "-x" | "--hex" => options.hex = true,
+            "-t" | "--trim" => options.trim = true,
+            "-n" | "--no-newline" => options.newline = false,
+            "-h" | "--help" => return Ok(None),
+            "-a" | "--alphabet" => {
+                let name = args
+                    .next()
+                    .ok_or_else(|| CliError::Usage(format!("{} needs an alphabet", arg)))?;
+
+                options.alphabet = parse_alphabet(&name)?;
+            }
+            "-" => options.paths.push(arg),
+            _ if arg.starts_with('-') => {
+                return Err(CliError::Usage(format!("unknown option {}", arg)));
+            }
+            _ => options.paths.push(arg),
+        }
+    }
+
+    if options.paths.is_empty() {
+        options.paths.push(String::from("-"));
+    }
+
+    Ok(Some(options))
+}
+
+fn parse_alphabet(name: &str) -> Result<Alphabet, CliError> {
+    match name {
+        "bitcoin" => Ok(Alphabet::BITCOIN),
+        "ripple" => Ok(Alphabet::RIPPLE),
+        "flickr" => Ok(Alphabet::FLICKR),
+        _ => Alphabet::new(name).map_err(|error| CliError::Usage(format!("alphabet: {}", error))),
+    }
+}
+
+fn read_input(path: &str) -> Result<Vec<u8>, CliError> {
+    let mut input = vec![];
+
+    let result = if path == "-" {
+        io::stdin().read_to_end(&mut input)
+    } else {
+        File::open(path).and_then(|mut file| file.read_to_end(&mut input))
+    };
+
+    result
+        .map(|_| input)
+        .map_err(|error| CliError::Io(error.to_string()))
+}
+
+fn run(options: &Options, input: &[u8]) -> Result<Vec<u8>, CliError> {
+    if options.decode {
+        run_decode(options, input)
+    } else {
+        run_encode(options, input)
+    }
+}
+
+fn run_encode(options: &Options, input: &[u8]) -> Result<Vec<u8>, CliError> {
+    let plain_bytes = if options.hex {
+        let (offset, hex_text) = trim_whitespace(input);
+
+        parse_hex(hex_text).map_err(|index| CliError::InvalidHex {
+            index: offset + index,
+        })?
+    } else if options.trim {
+        let length = input
+            .iter()
+            .rposition(|&byte| byte != b'\n' && byte != b'\r')
+            .map_or(0, |index| index + 1);
+
+        input[..length].to_vec()
+    } else {
+        input.to_vec()
+    };
+
+    let encoded_text = if options.check {
+        let (&version, payload) = plain_bytes.split_first().ok_or(CliError::MissingVersion)?;
+
+        check::encode_with_alphabet(version, payload, &options.alphabet)
+    } else {
+        base58_rs::encode_with_alphabet(&plain_bytes, &options.alphabet)
+    };
+
+    Ok(with_newline(options, encoded_text.into_bytes()))
+}
+
+fn run_decode(options: &Options, input: &[u8]) -> Result<Vec<u8>, CliError> {
+    let (offset, encoded_text) = trim_whitespace(input);
+
+    let result = if options.check {
+        check::decode_with_alphabet(encoded_text, &options.alphabet).map(
+            |(version, mut payload)| {
+                payload.insert(0, version);
+                payload
+            },
+        )
+    } else {
+        base58_rs::decode_with_alphabet(encoded_text, &options.alphabet)
+    };
+
+    let plain_bytes = result.map_err(|error| match error {
+        DecodeError::InvalidCharacter { character, index } => {
+            CliError::Decode(DecodeError::InvalidCharacter {
+                character,
+                index: offset + index,
+            })
+        }
+        _ => CliError::Decode(error),
+    })?;
+
+    if options.hex {
+        Ok(with_newline(options, format_hex(&plain_bytes).into_bytes()))
+    } else {
+        Ok(plain_bytes)
+    }
+}
+
+fn with_newline(options: &Options, mut text: Vec<u8>) -> Vec<u8> {
+    if options.newline {
+        text.push(b'\n');
+    }
+
+    text
+}
+
+fn trim_whitespace(input: &[u8]) -> (usize, &[u8]) {
+    let start = input
+        .iter()
+        .position(|byte| !byte.is_ascii_whitespace())
+        .unwrap_or(input.len());
+    let end = input
+        .iter()
+        .rposition(|byte| !byte.is_ascii_whitespace())
+        .map_or(start, |index| index + 1);
+
+    (start, &input[start..end])
+}
+
+fn parse_hex(hex_text: &[u8]) -> Result<Vec<u8>, usize> {
+    let digit = |index: usize| -> Result<u8, usize> {
+        hex_text
+            .get(index)
+            .and_then(|&ch| char::from(ch).to_digit(16))
+            .map(|value| value as u8)
+            .ok_or(index)
+    };
+
+    (0..hex_text.len())
+        .step_by(2)
+        .map(|index| Ok((digit(index)? << 4) | digit(index + 1)?))
+        .collect()
+}
+
+fn format_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(args: &[&str]) -> Options {
+        parse_options(args.iter().map(|arg| arg.to_string()))
+            .unwrap()
+            .unwrap()
+    }
+
+    #[test]
+    fn parse_options_should_work() {
+        let default_options = options(&[]);
+
+        assert!(!default_options.decode);
+        assert!(default_options.newline);
+        assert_eq!(Alphabet::BITCOIN, default_options.alphabet);
+        assert_eq!(vec![String::from("-")], default_options.paths);
+
+        let custom_options = options(&["-d", "--alphabet", "ripple", "-c", "-x", "-n", "a", "b"]);
+
+        assert!(custom_options.decode && custom_options.check && custom_options.hex);
+        assert!(!custom_options.newline);
+        assert_eq!(Alphabet::RIPPLE, custom_options.alphabet);
+        assert_eq!(
+            vec![String::from("a"), String::from("b")],
+            custom_options.paths
+        );
+
+        assert_eq!(
+            Ok(None),
+            parse_options(vec![String::from("-h")].into_iter())
+        );
+        assert!(parse_options(vec![String::from("--bogus")].into_iter()).is_err());
+        assert!(parse_options(vec![String::from("-a")].into_iter()).is_err());
+        assert!(
+            parse_options(vec![String::from("-a"), String::from("short")].into_iter()).is_err()
+        );
+    }
+
+    #[test]
+    fn hex_should_work() {
+        assert_eq!(Ok(vec![0x00, 0xab, 0xff]), parse_hex(b"00abFF"));
+        assert_eq!(Err(3), parse_hex(b"00ag"));
+        assert_eq!(Err(3), parse_hex(b"abc"));
+        assert_eq!("00abff", format_hex(&[0x00, 0xab, 0xff]));
+    }
+
+    #[test]
+    fn run_should_work() {
+        assert_eq!(
+            Ok(b"StV1DL6CwTryKyV\n".to_vec()),
+            run(&options(&[]), b"hello world")
+        );
+        assert_eq!(
+            Ok(b"StV1DL6CwTryKyV".to_vec()),
+            run(&options(&["-t", "-n"]), b"hello world\n")
+        );
+        assert_eq!(
+            Ok(b"hello world".to_vec()),
+            run(&options(&["-d"]), b"  StV1DL6CwTryKyV\n")
+        );
+        assert_eq!(
+            Ok(b"16UwLL9Risc3QfPqBUvKofHmBQ7wMtjvM\n".to_vec()),
+            run(
+                &options(&["-c", "-x"]),
+                b"00010966776006953d5567439e5e39f86a0d273bee\n"
+            )
+        );
+        assert_eq!(
+            Ok(b"00010966776006953d5567439e5e39f86a0d273bee\n".to_vec()),
+            run(
+                &options(&["-d", "-c", "-x"]),
+                b"16UwLL9Risc3QfPqBUvKofHmBQ7wMtjvM"
+            )
+        );
+    }
+
+    #[test]
+    fn run_should_report_error_positions() {
+        assert_eq!(
+            Err(CliError::Decode(DecodeError::InvalidCharacter {
+                character: 'O',
+                index: 6
+            })),
+            run(&options(&["-d"]), b"\n  StVO")
+        );
+        assert_eq!(
+            Err(CliError::InvalidHex { index: 2 }),
+            run(&options(&["-x"]), b" 0g")
+        );
+        assert_eq!(Err(CliError::MissingVersion), run(&options(&["-c"]), b""));
+    }
+}
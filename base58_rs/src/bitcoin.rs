@@ -0,0 +1,331 @@
+use alloc::string::String;
+use core::fmt;
+
+use super::check;
+use super::DecodeError;
+
+pub const PRIVATE_KEY_LENGTH: usize = 32;
+pub const HASH_LENGTH: usize = 20;
+
+const COMPRESSED_FLAG: u8 = 0x01;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Network {
+    Mainnet,
+    Testnet,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum AddressKind {
+    P2pkh,
+    P2sh,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct PrivateKey {
+    pub network: Network,
+    pub key: [u8; PRIVATE_KEY_LENGTH],
+    pub compressed: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Address {
+    pub network: Network,
+    pub kind: AddressKind,
+    pub hash: [u8; HASH_LENGTH],
+}
+
+impl Network {
+    fn wif_version(self) -> u8 {
+        match self {
+            Network::Mainnet => 0x80,
+            Network::Testnet => 0xef,
+        }
+    }
+
+    fn address_version(self, kind: AddressKind) -> u8 {
+        match (self, kind) {
+            (Network::Mainnet, AddressKind::P2pkh) => 0x00,
+            (Network::Mainnet, AddressKind::P2sh) => 0x05,
+            (Network::Testnet, AddressKind::P2pkh) => 0x6f,
+            (Network::Testnet, AddressKind::P2sh) => 0xc4,
+        }
+    }
+}
+
+impl PrivateKey {
+    pub fn new(network: Network, key: [u8; PRIVATE_KEY_LENGTH], compressed: bool) -> Self {
+        PrivateKey {
+            network,
+            key,
+            compressed,
+        }
+    }
+}
+
+impl Address {
+    pub fn p2pkh(network: Network, hash: [u8; HASH_LENGTH]) -> Self {
+        Address {
+            network,
+            kind: AddressKind::P2pkh,
+            hash,
+        }
+    }
+
+    pub fn p2sh(network: Network, hash: [u8; HASH_LENGTH]) -> Self {
+        Address {
+            network,
+            kind: AddressKind::P2sh,
+            hash,
+        }
+    }
+}
+
+pub fn encode_wif(private_key: &PrivateKey) -> String {
+    let mut payload = [0; PRIVATE_KEY_LENGTH + 1];
+    payload[..PRIVATE_KEY_LENGTH].copy_from_slice(&private_key.key);
+    payload[PRIVATE_KEY_LENGTH] = COMPRESSED_FLAG;
+
+    let payload_length = if private_key.compressed {
+        PRIVATE_KEY_LENGTH + 1
+    } else {
+        PRIVATE_KEY_LENGTH
+    };
+
+    check::encode(
+        private_key.network.wif_version(),
+        &payload[..payload_length],
+    )
+}
+
+pub fn decode_wif<T: AsRef<[u8]>>(input: T) -> Result<PrivateKey, DecodeError> {
+    let (version, payload) = check::decode(input)?;
+
+    let network = match version {
+        0x80 => Network::Mainnet,
+        0xef => Network::Testnet,
+        _ => return Err(DecodeError::InvalidVersion { version }),
+    };
+
+    let compressed = match payload.len() {
+        PRIVATE_KEY_LENGTH => false,
+        length
+            if length == PRIVATE_KEY_LENGTH + 1
+                && payload[PRIVATE_KEY_LENGTH] == COMPRESSED_FLAG =>
+        {
+            true
+        }
+        _ => return Err(DecodeError::InvalidLength),
+    };
+
+    let mut key = [0; PRIVATE_KEY_LENGTH];
+    key.copy_from_slice(&payload[..PRIVATE_KEY_LENGTH]);
+
+    Ok(PrivateKey {
+        network,
+        key,
+        compressed,
+    })
+}
+
+pub fn encode_address(address: &Address) -> String {
+    check::encode(address.network.address_version(address.kind), address.hash)
+}
+
+pub fn decode_address<T: AsRef<[u8]>>(input: T) -> Result<Address, DecodeError> {
+    let (version, payload) = check::decode(input)?;
+
+    let (network, kind) = match version {
+        0x00 => (Network::Mainnet, AddressKind::P2pkh),
+        0x05 => (Network::Mainnet, AddressKind::P2sh),
+        0x6f => (Network::Testnet, AddressKind::P2pkh),
+        0xc4 => (Network::Testnet, AddressKind::P2sh),
+        _ => return Err(DecodeError::InvalidVersion { version }),
+    };
+
+    if payload.len() != HASH_LENGTH {
+        return Err(DecodeError::InvalidLength);
+    }
+
+    let mut hash = [0; HASH_LENGTH];
+    hash.copy_from_slice(&payload);
+
+    Ok(Address {
+        network,
+        kind,
+        hash,
+    })
+}
+
+// NOTE: The key bytes are redacted so private keys do not end up in logs or panic messages.
+impl fmt::Debug for PrivateKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("PrivateKey")
+            .field("network", &self.network)
+            .field("key", &format_args!("<redacted>"))
+            .field("compressed", &self.compressed)
+            .finish()
+    }
+}
+
+impl fmt::Display for PrivateKey {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&encode_wif(self))
+    }
+}
+
+impl fmt::Display for Address {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&encode_address(self))
+    }
+}
+
+impl core::str::FromStr for PrivateKey {
+    type Err = DecodeError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        decode_wif(text)
+    }
+}
+
+impl core::str::FromStr for Address {
+    type Err = DecodeError;
+
+    fn from_str(text: &str) -> Result<Self, Self::Err> {
+        decode_address(text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{format, string::ToString, vec::Vec};
+
+    const PRIVATE_KEY: [u8; PRIVATE_KEY_LENGTH] = [
+        0x0c, 0x28, 0xfc, 0xa3, 0x86, 0xc7, 0xa2, 0x27, 0x60, 0x0b, 0x2f, 0xe5, 0x0b, 0x7c, 0xae,
+        0x11, 0xec, 0x86, 0xd3, 0xbf, 0x1f, 0xbe, 0x47, 0x1b, 0xe8, 0x98, 0x27, 0xe1, 0x9d, 0x72,
+        0xaa, 0x1d,
+    ];
+
+    const ADDRESS_HASH: [u8; HASH_LENGTH] = [
+        0x01, 0x09, 0x66, 0x77, 0x60, 0x06, 0x95, 0x3d, 0x55, 0x67, 0x43, 0x9e, 0x5e, 0x39, 0xf8,
+        0x6a, 0x0d, 0x27, 0x3b, 0xee,
+    ];
+
+    #[test]
+    fn static_wif_examples_should_work() {
+        let examples = [
+            (
+                PrivateKey::new(Network::Mainnet, PRIVATE_KEY, false),
+                "5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTLvyTJ",
+            ),
+            (
+                PrivateKey::new(Network::Mainnet, PRIVATE_KEY, true),
+                "KwdMAjGmerYanjeui5SHS7JkmpZvVipYvB2LJGU1ZxJwYvP98617",
+            ),
+            (
+                PrivateKey::new(Network::Testnet, PRIVATE_KEY, false),
+                "91gGn1HgSap6CbU12F6z3pJri26xzp7Ay1VW6NHCoEayNXwRpu2",
+            ),
+            (
+                PrivateKey::new(Network::Testnet, PRIVATE_KEY, true),
+                "cMzLdeGd5vEqxB8B6VFQoRopQ3sLAAvEzDAoQgvX54xwofSWj1fx",
+            ),
+        ];
+
+        for (private_key, wif) in examples.iter() {
+            assert_eq!(*wif, encode_wif(private_key));
+            assert_eq!(Ok(*private_key), decode_wif(wif));
+            assert_eq!(Ok(*private_key), wif.parse());
+        }
+    }
+
+    #[test]
+    fn invalid_wifs_should_fail() {
+        assert_eq!(
+            Err(DecodeError::InvalidLength),
+            decode_wif("KwdMAjGmerYanjeui5SHS7JkmpZvVipYvB2LJGU1ZxJwYvWxyf5d")
+        );
+        assert_eq!(
+            Err(DecodeError::InvalidLength),
+            decode_wif("yPoVP5njSzmEVK4VJGRWWAwqnwCyLPRcMm5XyrKgY1DE64xhu")
+        );
+        assert_eq!(
+            Err(DecodeError::InvalidVersion { version: 0x00 }),
+            decode_wif("16UwLL9Risc3QfPqBUvKofHmBQ7wMtjvM")
+        );
+        assert_eq!(
+            Err(DecodeError::InvalidChecksum),
+            decode_wif("5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTLvyTK")
+        );
+    }
+
+    #[test]
+    fn static_address_examples_should_work() {
+        let examples = [
+            (
+                Address::p2pkh(Network::Mainnet, ADDRESS_HASH),
+                "16UwLL9Risc3QfPqBUvKofHmBQ7wMtjvM",
+            ),
+            (
+                Address::p2sh(Network::Mainnet, ADDRESS_HASH),
+                "31nVrspaydBz8aMpxH9WkS2DuhgqS1fCuG",
+            ),
+            (
+                Address::p2pkh(Network::Testnet, ADDRESS_HASH),
+                "mfcSEPR8EkJrpX91YkTJ9iscdAzppJrG9j",
+            ),
+            (
+                Address::p2sh(Network::Testnet, ADDRESS_HASH),
+                "2MsLhvckcb5hLLMzNdQmPNP1V83u1HVdeEb",
+            ),
+        ];
+
+        for (address, text) in examples.iter() {
+            assert_eq!(*text, encode_address(address));
+            assert_eq!(*text, address.to_string());
+            assert_eq!(Ok(*address), decode_address(text));
+        }
+    }
+
+    #[test]
+    fn private_key_debug_should_redact_the_key() {
+        let private_key = PrivateKey::new(Network::Mainnet, PRIVATE_KEY, true);
+
+        assert_eq!(
+            "PrivateKey { network: Mainnet, key: <redacted>, compressed: true }",
+            format!("{:?}", private_key)
+        );
+    }
+
+    #[test]
+    fn invalid_addresses_should_fail() {
+        assert_eq!(
+            Err(DecodeError::InvalidVersion { version: 0x42 }),
+            decode_address("TZQHvW1AHdTS21s7SsTxL5dDJUQNqfwwzy")
+        );
+        assert_eq!(
+            Err(DecodeError::InvalidVersion { version: 0x80 }),
+            decode_address("5HueCGU8rMjxEXxiPuD5BDku4MkFqeZyd4dZ1jvhTVqvbTLvyTJ")
+        );
+        assert_eq!(Err(DecodeError::InvalidLength), decode_address("1Wh4bh"));
+    }
+
+    #[quickcheck]
+    fn encode_decode_wif_should_work(key: Vec<u8>, testnet: bool, compressed: bool) -> bool {
+        let mut key_bytes = [0; PRIVATE_KEY_LENGTH];
+
+        for (byte, &value) in key_bytes.iter_mut().zip(key.iter()) {
+            *byte = value;
+        }
+
+        let network = if testnet {
+            Network::Testnet
+        } else {
+            Network::Mainnet
+        };
+        let private_key = PrivateKey::new(network, key_bytes, compressed);
+
+        decode_wif(encode_wif(&private_key)) == Ok(private_key)
+    }
+}
@@ -0,0 +1,382 @@
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec::Vec};
+
+use super::sha256;
+use super::{decode_to_slice_with_alphabet, validate_with_alphabet as base58_validate};
+#[cfg(feature = "alloc")]
+use super::{decode_with_alphabet as base58_decode, encode_with_alphabet as base58_encode};
+use super::{Alphabet, DecodeError};
+
+pub const CHECKSUM_LENGTH: usize = 4;
+
+pub const MAX_VALIDATE_LENGTH: usize = 128;
+
+pub trait Checksum {
+    fn checksum(&self, plain_bytes: &[u8]) -> [u8; CHECKSUM_LENGTH];
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DoubleSha256;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Cb58;
+
+impl Checksum for DoubleSha256 {
+    fn checksum(&self, plain_bytes: &[u8]) -> [u8; CHECKSUM_LENGTH] {
+        let hash = sha256::double_digest(plain_bytes);
+
+        [hash[0], hash[1], hash[2], hash[3]]
+    }
+}
+
+impl Checksum for Cb58 {
+    fn checksum(&self, plain_bytes: &[u8]) -> [u8; CHECKSUM_LENGTH] {
+        let hash = sha256::digest(plain_bytes);
+
+        [hash[28], hash[29], hash[30], hash[31]]
+    }
+}
+
+#[cfg(feature = "alloc")]
+pub fn encode<T: AsRef<[u8]>>(version: u8, payload: T) -> String {
+    encode_with_alphabet(version, payload, &Alphabet::BITCOIN)
+}
+
+#[cfg(feature = "alloc")]
+pub fn encode_with_alphabet<T: AsRef<[u8]>>(
+    version: u8,
+    payload: T,
+    alphabet: &Alphabet,
+) -> String {
+    let payload = payload.as_ref();
+
+    let mut plain_bytes = Vec::with_capacity(1 + payload.len() + CHECKSUM_LENGTH);
+    plain_bytes.push(version);
+    plain_bytes.extend_from_slice(payload);
+
+    append_checksum(&mut plain_bytes, &DoubleSha256);
+
+    base58_encode(plain_bytes, alphabet)
+}
+
+#[cfg(feature = "alloc")]
+pub fn encode_with_checksum<T: AsRef<[u8]>, C: Checksum + ?Sized>(
+    input: T,
+    checksum: &C,
+) -> String {
+    encode_with_checksum_and_alphabet(input, checksum, &Alphabet::BITCOIN)
+}
+
+#[cfg(feature = "alloc")]
+pub fn encode_with_checksum_and_alphabet<T: AsRef<[u8]>, C: Checksum + ?Sized>(
+    input: T,
+    checksum: &C,
+    alphabet: &Alphabet,
+) -> String {
+    let input = input.as_ref();
+
+    let mut plain_bytes = Vec::with_capacity(input.len() + CHECKSUM_LENGTH);
+    plain_bytes.extend_from_slice(input);
+
+    append_checksum(&mut plain_bytes, checksum);
+
+    base58_encode(plain_bytes, alphabet)
+}
+
+#[cfg(feature = "alloc")]
+pub fn decode<T: AsRef<[u8]>>(input: T) -> Result<(u8, Vec<u8>), DecodeError> {
+    decode_with_alphabet(input, &Alphabet::BITCOIN)
+}
+
+#[cfg(feature = "alloc")]
+pub fn decode_with_alphabet<T: AsRef<[u8]>>(
+    input: T,
+    alphabet: &Alphabet,
+) -> Result<(u8, Vec<u8>), DecodeError> {
+    let mut plain_bytes = strip_checksum(base58_decode(input, alphabet)?, 1, &DoubleSha256)?;
+
+    let payload = plain_bytes.split_off(1);
+
+    Ok((plain_bytes[0], payload))
+}
+
+#[cfg(feature = "alloc")]
+pub fn decode_with_checksum<T: AsRef<[u8]>, C: Checksum + ?Sized>(
+    input: T,
+    checksum: &C,
+) -> Result<Vec<u8>, DecodeError> {
+    decode_with_checksum_and_alphabet(input, checksum, &Alphabet::BITCOIN)
+}
+
+#[cfg(feature = "alloc")]
+pub fn decode_with_checksum_and_alphabet<T: AsRef<[u8]>, C: Checksum + ?Sized>(
+    input: T,
+    checksum: &C,
+    alphabet: &Alphabet,
+) -> Result<Vec<u8>, DecodeError> {
+    strip_checksum(base58_decode(input, alphabet)?, 0, checksum)
+}
+
+pub fn is_valid<T: AsRef<[u8]>>(input: T, version: u8) -> bool {
+    validate(input, version).is_ok()
+}
+
+pub fn validate<T: AsRef<[u8]>>(input: T, version: u8) -> Result<(), DecodeError> {
+    validate_with_alphabet(input, version, &Alphabet::BITCOIN)
+}
+
+// NOTE: The checksum can only be verified on the decoded bytes, so they are decoded into a stack
+// buffer of `MAX_VALIDATE_LENGTH` bytes to keep validation in constant memory and available without
+// `alloc`. That covers the version byte, payload and checksum of every address and WIF key, which
+// are at most 38 bytes; anything longer is rejected with `PayloadTooLong` instead of being decoded.
+pub fn validate_with_alphabet<T: AsRef<[u8]>>(
+    input: T,
+    version: u8,
+    alphabet: &Alphabet,
+) -> Result<(), DecodeError> {
+    let encoded_bytes = input.as_ref();
+
+    base58_validate(encoded_bytes, alphabet)?;
+
+    let mut plain_bytes = [0; MAX_VALIDATE_LENGTH];
+    let length = decode_to_slice_with_alphabet(encoded_bytes, &mut plain_bytes, alphabet).map_err(
+        |error| match error {
+            DecodeError::BufferTooSmall => DecodeError::PayloadTooLong {
+                max_length: MAX_VALIDATE_LENGTH,
+            },
+            error => error,
+        },
+    )?;
+
+    verify_checksum(&plain_bytes[..length], 1, &DoubleSha256)?;
+
+    if plain_bytes[0] != version {
+        return Err(DecodeError::InvalidVersion {
+            version: plain_bytes[0],
+        });
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "alloc")]
+fn append_checksum<C: Checksum + ?Sized>(plain_bytes: &mut Vec<u8>, checksum: &C) {
+    let checksum = checksum.checksum(plain_bytes);
+
+    plain_bytes.extend_from_slice(&checksum);
+}
+
+#[cfg(feature = "alloc")]
+fn strip_checksum<C: Checksum + ?Sized>(
+    mut plain_bytes: Vec<u8>,
+    min_length: usize,
+    checksum: &C,
+) -> Result<Vec<u8>, DecodeError> {
+    let checksum_index = verify_checksum(&plain_bytes, min_length, checksum)?;
+
+    plain_bytes.truncate(checksum_index);
+
+    Ok(plain_bytes)
+}
+
+fn verify_checksum<C: Checksum + ?Sized>(
+    plain_bytes: &[u8],
+    min_length: usize,
+    checksum: &C,
+) -> Result<usize, DecodeError> {
+    if plain_bytes.len() < min_length + CHECKSUM_LENGTH {
+        return Err(DecodeError::InvalidLength);
+    }
+
+    let checksum_index = plain_bytes.len() - CHECKSUM_LENGTH;
+
+    if plain_bytes[checksum_index..] != checksum.checksum(&plain_bytes[..checksum_index]) {
+        return Err(DecodeError::InvalidChecksum);
+    }
+
+    Ok(checksum_index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    const ADDRESS_HASH: [u8; 20] = [
+        0x01, 0x09, 0x66, 0x77, 0x60, 0x06, 0x95, 0x3d, 0x55, 0x67, 0x43, 0x9e, 0x5e, 0x39, 0xf8,
+        0x6a, 0x0d, 0x27, 0x3b, 0xee,
+    ];
+
+    #[test]
+    fn static_examples_should_work() {
+        assert_eq!(
+            "16UwLL9Risc3QfPqBUvKofHmBQ7wMtjvM",
+            encode(0x00, ADDRESS_HASH)
+        );
+        assert_eq!("1Wh4bh", encode(0x00, b""));
+
+        assert_eq!(
+            Ok((0x00, ADDRESS_HASH.to_vec())),
+            decode("16UwLL9Risc3QfPqBUvKofHmBQ7wMtjvM")
+        );
+        assert_eq!(Ok((0x00, vec![])), decode("1Wh4bh"));
+    }
+
+    #[test]
+    fn invalid_examples_should_fail() {
+        assert_eq!(
+            Err(DecodeError::InvalidChecksum),
+            decode("16UwLL9Risc3QfPqBUvKofHmBQ7wMtjvN")
+        );
+        assert_eq!(Err(DecodeError::InvalidLength), decode(""));
+        assert_eq!(Err(DecodeError::InvalidLength), decode("1111"));
+        assert_eq!(
+            Err(DecodeError::InvalidCharacter {
+                character: '0',
+                index: 0
+            }),
+            decode("0Wh4bh")
+        );
+    }
+
+    #[test]
+    fn static_ripple_examples_should_work() {
+        let account_id = [0; 20];
+
+        assert_eq!(
+            "rrrrrrrrrrrrrrrrrrrrrhoLvTp",
+            encode_with_alphabet(0x00, account_id, &Alphabet::RIPPLE)
+        );
+        assert_eq!(
+            Ok((0x00, account_id.to_vec())),
+            decode_with_alphabet("rrrrrrrrrrrrrrrrrrrrrhoLvTp", &Alphabet::RIPPLE)
+        );
+    }
+
+    #[quickcheck]
+    fn encode_decode_should_work(version: u8, payload: Vec<u8>) -> bool {
+        decode(encode(version, &payload)) == Ok((version, payload))
+    }
+
+    #[quickcheck]
+    fn corrupted_checksum_should_fail(version: u8, payload: Vec<u8>, flip: u8) -> bool {
+        let mut plain_bytes = base58_decode(encode(version, &payload), &Alphabet::BITCOIN).unwrap();
+
+        let flip_index = plain_bytes.len() - 1 - usize::from(flip) % CHECKSUM_LENGTH;
+        plain_bytes[flip_index] ^= 0x01;
+
+        decode(base58_encode(plain_bytes, &Alphabet::BITCOIN)) == Err(DecodeError::InvalidChecksum)
+    }
+
+    #[test]
+    fn static_cb58_examples_should_work() {
+        let plain_bytes: Vec<u8> = (0..32).collect();
+
+        assert_eq!(
+            "16qJFWMMHFy3xDdLmvUeyc2S6FrWRhJP51HsvDYdz9cWcm5W",
+            encode_with_checksum(&plain_bytes, &Cb58)
+        );
+        assert_eq!("45PJLL", encode_with_checksum(b"", &Cb58));
+        assert_eq!(
+            "3vQB7B6MrGQZaxCvEpwu2",
+            encode_with_checksum("hello world", &Cb58)
+        );
+
+        assert_eq!(
+            Ok(plain_bytes),
+            decode_with_checksum("16qJFWMMHFy3xDdLmvUeyc2S6FrWRhJP51HsvDYdz9cWcm5W", &Cb58)
+        );
+        assert_eq!(
+            Err(DecodeError::InvalidChecksum),
+            decode_with_checksum("3vQB7B6MrGQZaxCvEpwu3", &Cb58)
+        );
+        assert_eq!(
+            Err(DecodeError::InvalidLength),
+            decode_with_checksum("111", &Cb58)
+        );
+    }
+
+    #[test]
+    fn versioned_and_checksum_encodings_should_agree() {
+        let mut versioned_bytes = vec![0x00];
+        versioned_bytes.extend_from_slice(&ADDRESS_HASH);
+
+        assert_eq!(
+            encode(0x00, ADDRESS_HASH),
+            encode_with_checksum(&versioned_bytes, &DoubleSha256)
+        );
+        assert_eq!(
+            "16UwLL9Risc3QfPqBUvKofHmBQ7wpqQvf",
+            encode_with_checksum(&versioned_bytes, &Cb58)
+        );
+    }
+
+    #[quickcheck]
+    fn encode_decode_with_checksum_should_work(plain_bytes: Vec<u8>) -> bool {
+        let checksums: [&dyn Checksum; 2] = [&DoubleSha256, &Cb58];
+
+        checksums.iter().all(|checksum| {
+            decode_with_checksum(encode_with_checksum(&plain_bytes, *checksum), *checksum)
+                == Ok(plain_bytes.clone())
+        })
+    }
+
+    #[test]
+    fn static_validate_examples_should_work() {
+        assert!(is_valid("16UwLL9Risc3QfPqBUvKofHmBQ7wMtjvM", 0x00));
+        assert!(!is_valid("16UwLL9Risc3QfPqBUvKofHmBQ7wMtjvM", 0x05));
+
+        assert_eq!(
+            Err(DecodeError::InvalidVersion { version: 0x00 }),
+            validate("16UwLL9Risc3QfPqBUvKofHmBQ7wMtjvM", 0x05)
+        );
+        assert_eq!(
+            Err(DecodeError::InvalidChecksum),
+            validate("16UwLL9Risc3QfPqBUvKofHmBQ7wMtjvN", 0x00)
+        );
+        assert_eq!(Err(DecodeError::InvalidLength), validate("1111", 0x00));
+        assert_eq!(
+            Err(DecodeError::InvalidCharacter {
+                character: '0',
+                index: 0
+            }),
+            validate("0Wh4bh", 0x00)
+        );
+        assert_eq!(
+            Ok(()),
+            validate_with_alphabet("rrrrrrrrrrrrrrrrrrrrrhoLvTp", 0x00, &Alphabet::RIPPLE)
+        );
+        assert_eq!(
+            Ok(()),
+            validate(encode(0x00, [0xff; MAX_VALIDATE_LENGTH - 5]), 0x00)
+        );
+        assert_eq!(
+            Err(DecodeError::PayloadTooLong {
+                max_length: MAX_VALIDATE_LENGTH
+            }),
+            validate(encode(0x00, [0xff; MAX_VALIDATE_LENGTH - 4]), 0x00)
+        );
+    }
+
+    #[quickcheck]
+    fn validate_should_match_decode(version: u8, mut payload: Vec<u8>, flip: bool) -> bool {
+        payload.truncate(MAX_VALIDATE_LENGTH - 5);
+
+        let mut encoded_text = encode(version, &payload);
+
+        if flip {
+            encoded_text.pop();
+            encoded_text.push('1');
+        }
+
+        let expected = decode(&encoded_text).and_then(|(actual_version, _)| match actual_version {
+            actual_version if actual_version == version => Ok(()),
+            actual_version => Err(DecodeError::InvalidVersion {
+                version: actual_version,
+            }),
+        });
+
+        validate(&encoded_text, version) == expected
+            && validate(&encoded_text, version.wrapping_add(1)).is_err()
+    }
+}
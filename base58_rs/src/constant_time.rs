@@ -0,0 +1,343 @@
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec, vec::Vec};
+use core::hint::black_box;
+
+use super::{Alphabet, DecodeError, EncodeError};
+
+pub use super::max_encoded_len;
+
+// NOTE: Every loop here runs a number of times that only depends on the input and output lengths,
+// and every table is scanned whole instead of indexed by a secret value. The encoded length itself
+// still depends on the value, as it does for any Base58 encoding, and decoding returns early on an
+// error, which only reveals that the input was invalid.
+
+#[cfg(feature = "alloc")]
+pub fn encode<T: AsRef<[u8]>>(input: T) -> String {
+    encode_with_alphabet(input, &Alphabet::BITCOIN)
+}
+
+#[cfg(feature = "alloc")]
+pub fn encode_with_alphabet<T: AsRef<[u8]>>(input: T, alphabet: &Alphabet) -> String {
+    let plain_bytes = input.as_ref();
+
+    let mut encoded_bytes = vec![0; max_encoded_len(plain_bytes.len())];
+    let length = encode_digits(plain_bytes, &mut encoded_bytes, alphabet);
+
+    encoded_bytes[..length]
+        .iter()
+        .map(|&ch| char::from(ch))
+        .collect()
+}
+
+pub fn encode_to_slice<T: AsRef<[u8]>>(input: T, output: &mut [u8]) -> Result<usize, EncodeError> {
+    encode_to_slice_with_alphabet(input, output, &Alphabet::BITCOIN)
+}
+
+pub fn encode_to_slice_with_alphabet<T: AsRef<[u8]>>(
+    input: T,
+    output: &mut [u8],
+    alphabet: &Alphabet,
+) -> Result<usize, EncodeError> {
+    let plain_bytes = input.as_ref();
+    let width = max_encoded_len(plain_bytes.len());
+
+    if output.len() < width {
+        return Err(EncodeError::BufferTooSmall);
+    }
+
+    let (digits, rest) = output.split_at_mut(width);
+    clear(rest);
+
+    Ok(encode_digits(plain_bytes, digits, alphabet))
+}
+
+fn encode_digits(plain_bytes: &[u8], digits: &mut [u8], alphabet: &Alphabet) -> usize {
+    let width = digits.len();
+
+    clear(digits);
+
+    for &byte in plain_bytes {
+        let mut carry = u32::from(byte);
+
+        for digit in digits.iter_mut() {
+            carry += u32::from(*digit) << 8;
+            *digit = (carry % 58) as u8;
+            carry /= 58;
+        }
+    }
+
+    let zero_count = leading_count(plain_bytes, 0);
+    let digit_count = significant_count(digits);
+    let length = zero_count + digit_count;
+
+    digits.reverse();
+    shift_left(digits, width - length);
+
+    for (index, digit) in digits.iter_mut().enumerate() {
+        let ch = lookup_character(alphabet.characters(), *digit);
+
+        *digit = select_u8(less_than(index, length), ch, 0);
+    }
+
+    length
+}
+
+#[cfg(feature = "alloc")]
+pub fn decode<T: AsRef<[u8]>>(input: T, output_length: usize) -> Result<Vec<u8>, DecodeError> {
+    decode_with_alphabet(input, output_length, &Alphabet::BITCOIN)
+}
+
+#[cfg(feature = "alloc")]
+pub fn decode_with_alphabet<T: AsRef<[u8]>>(
+    input: T,
+    output_length: usize,
+    alphabet: &Alphabet,
+) -> Result<Vec<u8>, DecodeError> {
+    let mut plain_bytes = vec![0; output_length];
+    let length = decode_to_slice_with_alphabet(input, &mut plain_bytes, alphabet)?;
+
+    plain_bytes.truncate(length);
+
+    Ok(plain_bytes)
+}
+
+pub fn decode_to_slice<T: AsRef<[u8]>>(input: T, output: &mut [u8]) -> Result<usize, DecodeError> {
+    decode_to_slice_with_alphabet(input, output, &Alphabet::BITCOIN)
+}
+
+pub fn decode_to_slice_with_alphabet<T: AsRef<[u8]>>(
+    input: T,
+    output: &mut [u8],
+    alphabet: &Alphabet,
+) -> Result<usize, DecodeError> {
+    let encoded_bytes = input.as_ref();
+    let width = output.len();
+
+    clear(output);
+
+    let mut invalid_index = encoded_bytes.len();
+    let mut overflow = 0;
+
+    for (index, &ch) in encoded_bytes.iter().enumerate() {
+        let (value, valid) = lookup_value(alphabet.characters(), ch);
+
+        invalid_index = select_usize(
+            (valid ^ 1) & equals_usize(invalid_index, encoded_bytes.len()),
+            index,
+            invalid_index,
+        );
+
+        let mut carry = u32::from(value);
+
+        for byte in output.iter_mut() {
+            carry += u32::from(*byte) * 58;
+            *byte = carry as u8;
+            carry >>= 8;
+        }
+
+        overflow |= is_nonzero(carry);
+    }
+
+    let zero_count = leading_count(encoded_bytes, alphabet.characters()[0]);
+    let byte_count = significant_count(output);
+    let length = zero_count + byte_count;
+
+    overflow |= less_than(width, length);
+
+    if invalid_index < encoded_bytes.len() {
+        clear(output);
+        return Err(DecodeError::invalid_character(encoded_bytes, invalid_index));
+    }
+
+    if overflow != 0 {
+        clear(output);
+        return Err(DecodeError::BufferTooSmall);
+    }
+
+    output.reverse();
+    shift_left(output, width - length);
+
+    Ok(length)
+}
+
+fn mask(bit: u32) -> u32 {
+    black_box(0_u32.wrapping_sub(bit))
+}
+
+fn is_nonzero(value: u32) -> u32 {
+    (value | value.wrapping_neg()) >> 31
+}
+
+fn equals(left: u8, right: u8) -> u32 {
+    is_nonzero(u32::from(left ^ right)) ^ 1
+}
+
+fn equals_usize(left: usize, right: usize) -> u32 {
+    let difference = (left ^ right) as u64;
+
+    (((difference | difference.wrapping_neg()) >> 63) ^ 1) as u32
+}
+
+fn less_than(left: usize, right: usize) -> u32 {
+    ((left as u64).wrapping_sub(right as u64) >> 63) as u32
+}
+
+fn select_u8(bit: u32, if_set: u8, if_unset: u8) -> u8 {
+    if_unset ^ (mask(bit) as u8 & (if_set ^ if_unset))
+}
+
+fn select_usize(bit: u32, if_set: usize, if_unset: usize) -> usize {
+    let mask = u64::from(mask(bit)) | u64::from(mask(bit)) << 32;
+
+    if_unset ^ (mask as usize & (if_set ^ if_unset))
+}
+
+fn clear(bytes: &mut [u8]) {
+    for byte in bytes.iter_mut() {
+        *byte = black_box(0);
+    }
+}
+
+fn leading_count(bytes: &[u8], target: u8) -> usize {
+    let mut count = 0;
+    let mut leading = 1;
+
+    for &byte in bytes {
+        leading &= equals(byte, target);
+        count += leading as usize;
+    }
+
+    count
+}
+
+fn significant_count(little_endian_bytes: &[u8]) -> usize {
+    let mut count = 0;
+
+    for (index, &byte) in little_endian_bytes.iter().enumerate() {
+        count = select_usize(is_nonzero(u32::from(byte)), index + 1, count);
+    }
+
+    count
+}
+
+fn shift_left(bytes: &mut [u8], shift: usize) {
+    let mut bit = 0;
+
+    while bit < usize::BITS && 1 << bit < bytes.len() {
+        let step = 1 << bit;
+        let choice = ((shift >> bit) & 1) as u32;
+
+        for index in 0..bytes.len() {
+            let shifted = bytes.get(index + step).copied().unwrap_or(0);
+
+            bytes[index] = select_u8(choice, shifted, bytes[index]);
+        }
+
+        bit += 1;
+    }
+}
+
+fn lookup_character(characters: &[u8], value: u8) -> u8 {
+    characters
+        .iter()
+        .enumerate()
+        .fold(0, |ch, (index, &candidate)| {
+            ch | (mask(equals(index as u8, value)) as u8 & candidate)
+        })
+}
+
+fn lookup_value(characters: &[u8], ch: u8) -> (u8, u32) {
+    characters
+        .iter()
+        .enumerate()
+        .fold((0, 0), |(value, valid), (index, &candidate)| {
+            let found = equals(candidate, ch);
+
+            (value | (mask(found) as u8 & index as u8), valid | found)
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn static_examples_should_work() {
+        let mut output = [0; 64];
+
+        assert_eq!(Ok(15), encode_to_slice("hello world", &mut output));
+        assert_eq!(b"StV1DL6CwTryKyV", &output[..15]);
+        assert!(output[15..].iter().all(|&byte| byte == 0));
+
+        assert_eq!("11ZiCa", encode(b"\0\0abc"));
+        assert_eq!("1111", encode([0; 4]));
+        assert_eq!("", encode(b""));
+        assert_eq!(
+            "rStVrDLaUATiyKyV",
+            encode_with_alphabet("\0hello world", &Alphabet::RIPPLE)
+        );
+
+        assert_eq!(Ok(b"hello world".to_vec()), decode("StV1DL6CwTryKyV", 11));
+        assert_eq!(Ok(b"\0\0abc".to_vec()), decode("11ZiCa", 32));
+        assert_eq!(Ok(vec![0; 4]), decode("1111", 4));
+        assert_eq!(
+            Err(DecodeError::InvalidCharacter {
+                character: 'l',
+                index: 4
+            }),
+            decode("StV1lOI", 32)
+        );
+        assert_eq!(
+            Err(DecodeError::BufferTooSmall),
+            decode("StV1DL6CwTryKyV", 10)
+        );
+        assert_eq!(Err(DecodeError::BufferTooSmall), decode("1111", 3));
+    }
+
+    #[test]
+    fn fixed_length_keys_should_work() {
+        let mut output = [0; 44];
+
+        for key in &[[0; 32], [0xff; 32], [0x01; 32]] {
+            let length = encode_to_slice(key, &mut output).unwrap();
+
+            assert_eq!(super::super::encode(key).as_bytes(), &output[..length]);
+
+            let mut plain_bytes = [0; 32];
+
+            assert_eq!(Ok(32), decode_to_slice(&output[..length], &mut plain_bytes));
+            assert_eq!(key, &plain_bytes);
+        }
+
+        assert_eq!(44, max_encoded_len(32));
+        assert_eq!(
+            Err(EncodeError::BufferTooSmall),
+            encode_to_slice([0xff; 32], &mut output[..43])
+        );
+    }
+
+    #[quickcheck]
+    fn encode_should_match_variable_time(bytes: Vec<u8>) -> bool {
+        encode(&bytes) == super::super::encode(&bytes)
+    }
+
+    #[quickcheck]
+    fn decode_should_match_variable_time(bytes: Vec<u8>, extra_length: u8) -> bool {
+        let encoded_text = super::super::encode(&bytes);
+        let output_length = bytes.len() + usize::from(extra_length % 8);
+
+        decode(&encoded_text, output_length) == Ok(bytes.clone())
+            && (bytes.is_empty()
+                || decode(&encoded_text, bytes.len() - 1) == Err(DecodeError::BufferTooSmall))
+    }
+
+    #[quickcheck]
+    fn decode_errors_should_match_variable_time(text: String) -> bool {
+        let expected = super::super::decode(&text);
+
+        match decode(&text, text.len()) {
+            Ok(plain_bytes) => expected == Ok(plain_bytes),
+            Err(error) => expected == Err(error),
+        }
+    }
+}
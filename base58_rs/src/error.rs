@@ -0,0 +1,122 @@
+use core::fmt;
+use core::str;
+#[cfg(feature = "std")]
+use std::error::Error;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EncodeError {
+    BufferTooSmall,
+    InvalidLength,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    BlockOverflow { index: usize },
+    BufferTooSmall,
+    InvalidCharacter { character: char, index: usize },
+    InvalidChecksum,
+    InvalidLength,
+    InvalidVersion { version: u8 },
+    NonUtf8Output { valid_up_to: usize },
+    PayloadTooLong { max_length: usize },
+}
+
+impl DecodeError {
+    pub(crate) fn invalid_character(encoded_bytes: &[u8], index: usize) -> Self {
+        let remaining_bytes = &encoded_bytes[index..];
+
+        let character = (1..=remaining_bytes.len().min(4))
+            .filter_map(|length| str::from_utf8(&remaining_bytes[..length]).ok())
+            .filter_map(|text| text.chars().next())
+            .next()
+            .unwrap_or(char::REPLACEMENT_CHARACTER);
+
+        DecodeError::InvalidCharacter { character, index }
+    }
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            EncodeError::BufferTooSmall => write!(f, "output buffer is too small"),
+            EncodeError::InvalidLength => write!(f, "invalid input length"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for EncodeError {}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DecodeError::BlockOverflow { index } => {
+                write!(f, "block at byte {} overflows its length", index)
+            }
+            DecodeError::BufferTooSmall => write!(f, "output buffer is too small"),
+            DecodeError::InvalidCharacter { character, index } => {
+                write!(f, "invalid character {:?} at byte {}", character, index)
+            }
+            DecodeError::InvalidChecksum => write!(f, "invalid checksum"),
+            DecodeError::InvalidLength => write!(f, "invalid length"),
+            DecodeError::InvalidVersion { version } => {
+                write!(f, "unexpected version byte {:#04x}", version)
+            }
+            DecodeError::NonUtf8Output { valid_up_to } => write!(
+                f,
+                "decoded bytes are not valid UTF-8 after byte {}",
+                valid_up_to
+            ),
+            DecodeError::PayloadTooLong { max_length } => {
+                write!(f, "decoded payload is longer than {} bytes", max_length)
+            }
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for DecodeError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn invalid_character_should_report_the_character() {
+        assert_eq!(
+            DecodeError::InvalidCharacter {
+                character: '0',
+                index: 1
+            },
+            DecodeError::invalid_character(b"10", 1)
+        );
+        assert_eq!(
+            DecodeError::InvalidCharacter {
+                character: 'é',
+                index: 1
+            },
+            DecodeError::invalid_character("1é1".as_bytes(), 1)
+        );
+        assert_eq!(
+            DecodeError::InvalidCharacter {
+                character: char::REPLACEMENT_CHARACTER,
+                index: 0
+            },
+            DecodeError::invalid_character(&[0xff, 0x31], 0)
+        );
+    }
+
+    #[test]
+    fn display_should_work() {
+        assert_eq!(
+            "invalid character 'O' at byte 3",
+            DecodeError::InvalidCharacter {
+                character: 'O',
+                index: 3
+            }
+            .to_string()
+        );
+        assert_eq!("invalid checksum", DecodeError::InvalidChecksum.to_string());
+    }
+}
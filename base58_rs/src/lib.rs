@@ -1,7 +1,7 @@
-extern crate num;
-extern crate num_bigint;
-extern crate num_integer;
-extern crate num_traits;
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
 
 #[cfg(test)]
 extern crate quickcheck;
@@ -13,113 +13,414 @@ extern crate quickcheck_macros;
 #[cfg(test)]
 extern crate rust_base58;
 
-use num_bigint::{BigUint, ToBigUint};
-use num_integer::Integer;
-use num_traits::{ToPrimitive, Zero};
+#[cfg(test)]
+extern crate sha2;
 
-pub fn encode(plain_text: &String) -> String {
-    if plain_text == "" {
-        return plain_text.to_owned();
-    }
+#[cfg(all(test, feature = "serde"))]
+extern crate serde_json;
 
-    let characters: Vec<u8> = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz".into();
+mod alphabet;
+pub mod ascii85;
+#[cfg(feature = "alloc")]
+pub mod base32;
+pub mod basen;
+#[cfg(feature = "alloc")]
+pub mod bech32;
+#[cfg(feature = "alloc")]
+pub mod bitcoin;
+pub mod check;
+pub mod constant_time;
+mod error;
+#[cfg(feature = "alloc")]
+pub mod monero;
+#[cfg(feature = "alloc")]
+pub mod multibase;
+#[cfg(feature = "serde")]
+pub mod serde;
+mod sha256;
+#[cfg(feature = "std")]
+pub mod stream;
+pub mod z85;
 
-    let value_256 =
-        (*plain_text.as_bytes())
-            .into_iter()
-            .fold(Zero::zero(), |acc: BigUint, byte| {
-                let value = byte.to_biguint().unwrap();
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec, vec::Vec};
 
-                acc * (256 as usize) + value
-            });
+pub use alphabet::{Alphabet, AlphabetError};
+pub use basen::BaseN;
+pub use error::{DecodeError, EncodeError};
 
-    if value_256.is_zero() {
-        return String::from_utf8_lossy(&characters[0..1]).into_owned();
-    }
+pub fn max_encoded_len(input_length: usize) -> usize {
+    // 1.365659 is just above log(256) / log(58), the number of digits needed per byte.
+    (input_length as u128 * 1_365_659).div_ceil(1_000_000) as usize
+}
 
-    let mut coefficients_58 = vec![];
-    let mut value = value_256.clone();
+pub fn max_decoded_len(input_length: usize) -> usize {
+    input_length
+}
 
-    let divisor = BigUint::from(58 as usize);
+#[cfg(feature = "alloc")]
+pub fn encode<T: AsRef<[u8]>>(input: T) -> String {
+    encode_with_alphabet(input, &Alphabet::BITCOIN)
+}
 
-    while !value.is_zero() {
-        coefficients_58.push(&value % (58 as usize));
+#[cfg(feature = "alloc")]
+pub fn encode_with_alphabet<T: AsRef<[u8]>>(input: T, alphabet: &Alphabet) -> String {
+    let plain_bytes = input.as_ref();
 
-        value = value.div_floor(&divisor);
-    }
+    let mut encoded_bytes = vec![0; max_encoded_len(plain_bytes.len())];
 
-    coefficients_58.reverse();
+    // NOTE: `max_encoded_len` rounds up the number of Base58 digits needed per byte, so it is never
+    // shorter than an encoding and the buffer cannot be too small.
+    let length = match encode_to_slice_with_alphabet(plain_bytes, &mut encoded_bytes, alphabet) {
+        Ok(length) => length,
+        Err(EncodeError::BufferTooSmall) => unreachable!("encoding is longer than max_encoded_len"),
+        Err(EncodeError::InvalidLength) => unreachable!("Base58 encodes input of any length"),
+    };
 
-    String::from_utf8_lossy(
-        &coefficients_58
-            .iter()
-            .map(|value| value.to_usize().unwrap())
-            .map(|coefficient| characters.get(coefficient).unwrap().clone())
-            .collect::<Vec<u8>>(),
-    )
-    .into_owned()
+    encoded_bytes[..length]
+        .iter()
+        .map(|&ch| char::from(ch))
+        .collect()
 }
 
-pub fn decode(encoded_text: &String) -> Option<String> {
-    if encoded_text == "" {
-        return Some("".to_string());
-    }
+pub fn encode_to_slice<T: AsRef<[u8]>>(input: T, output: &mut [u8]) -> Result<usize, EncodeError> {
+    encode_to_slice_with_alphabet(input, output, &Alphabet::BITCOIN)
+}
 
-    let characters: Vec<u8> = "123456789ABCDEFGHJKLMNPQRSTUVWXYZabcdefghijkmnopqrstuvwxyz".into();
+pub fn encode_to_slice_with_alphabet<T: AsRef<[u8]>>(
+    input: T,
+    output: &mut [u8],
+    alphabet: &Alphabet,
+) -> Result<usize, EncodeError> {
+    alphabet.base().encode_to_slice(input, output)
+}
 
-    let value_58_result =
-        (*encoded_text.as_bytes())
-            .into_iter()
-            .try_fold(Zero::zero(), |acc: BigUint, ch| {
-                characters
-                    .iter()
-                    .position(|v| v == ch)
-                    .map(|value| acc * (58 as usize) + value)
-            });
+#[cfg(feature = "alloc")]
+pub fn decode<T: AsRef<[u8]>>(input: T) -> Result<Vec<u8>, DecodeError> {
+    decode_with_alphabet(input, &Alphabet::BITCOIN)
+}
 
-    value_58_result.and_then(|value_58| {
-        if value_58.is_zero() {
-            return Some("\u{0}".to_string());
-        }
+#[cfg(feature = "alloc")]
+pub fn decode_with_alphabet<T: AsRef<[u8]>>(
+    input: T,
+    alphabet: &Alphabet,
+) -> Result<Vec<u8>, DecodeError> {
+    let encoded_bytes = input.as_ref();
 
-        let mut coefficients_256 = vec![];
-        let mut value = value_58.clone();
+    let mut plain_bytes = vec![0; max_decoded_len(encoded_bytes.len())];
+    let length = decode_to_slice_with_alphabet(encoded_bytes, &mut plain_bytes, alphabet)?;
 
-        let divisor = BigUint::from(256 as usize);
+    plain_bytes.truncate(length);
 
-        while !value.is_zero() {
-            let remainder = &value % (256 as usize);
-            coefficients_256.push(remainder.to_u8().unwrap());
+    Ok(plain_bytes)
+}
 
-            value = value.div_floor(&divisor);
-        }
+pub fn decode_to_slice<T: AsRef<[u8]>>(input: T, output: &mut [u8]) -> Result<usize, DecodeError> {
+    decode_to_slice_with_alphabet(input, output, &Alphabet::BITCOIN)
+}
 
-        coefficients_256.reverse();
+pub fn decode_to_slice_with_alphabet<T: AsRef<[u8]>>(
+    input: T,
+    output: &mut [u8],
+    alphabet: &Alphabet,
+) -> Result<usize, DecodeError> {
+    alphabet.base().decode_to_slice(input, output)
+}
 
-        String::from_utf8(coefficients_256).ok()
+#[cfg(feature = "alloc")]
+pub fn decode_string(encoded_text: &str) -> Result<String, DecodeError> {
+    String::from_utf8(decode(encoded_text)?).map_err(|error| DecodeError::NonUtf8Output {
+        valid_up_to: error.utf8_error().valid_up_to(),
     })
 }
 
+pub fn is_valid<T: AsRef<[u8]>>(input: T) -> bool {
+    validate(input).is_ok()
+}
+
+pub fn validate<T: AsRef<[u8]>>(input: T) -> Result<(), DecodeError> {
+    validate_with_alphabet(input, &Alphabet::BITCOIN)
+}
+
+pub fn validate_with_alphabet<T: AsRef<[u8]>>(
+    input: T,
+    alphabet: &Alphabet,
+) -> Result<(), DecodeError> {
+    alphabet.base().validate(input)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     use rust_base58::base58::{FromBase58, ToBase58};
 
+    #[test]
+    fn static_examples_should_work() {
+        assert_eq!("", encode(b""));
+        assert_eq!("2g", encode(b"a"));
+        assert_eq!("5Q", encode([0xff]));
+        assert_eq!("StV1DL6CwTryKyV", encode("hello world"));
+
+        assert_eq!(Ok(b"hello world".to_vec()), decode("StV1DL6CwTryKyV"));
+        assert_eq!(Ok(vec![0xff]), decode("5Q"));
+        assert_eq!(
+            Err(DecodeError::InvalidCharacter {
+                character: '0',
+                index: 0
+            }),
+            decode("0OIl")
+        );
+        assert_eq!(
+            Err(DecodeError::InvalidCharacter {
+                character: 'l',
+                index: 4
+            }),
+            decode("StV1l")
+        );
+    }
+
+    #[test]
+    fn leading_zeros_should_be_preserved() {
+        assert_eq!("1", encode([0]));
+        assert_eq!("111", encode([0, 0, 0]));
+        assert_eq!("11ZiCa", encode(b"\0\0abc"));
+        assert_eq!("11StV1DL6CwTryKyV", encode(b"\0\0hello world"));
+
+        assert_eq!(Ok(vec![]), decode(""));
+        assert_eq!(Ok(vec![0]), decode("1"));
+        assert_eq!(Ok(vec![0, 0, 0]), decode("111"));
+        assert_eq!(Ok(b"\0\0abc".to_vec()), decode("11ZiCa"));
+    }
+
     #[quickcheck]
     fn encode_should_work(text: String) -> bool {
-        encode(&text) == *(&text.as_bytes()).to_base58()
+        encode(&text) == text.as_bytes().to_base58()
     }
 
     #[quickcheck]
-    fn encode_decode_should_work(text: String) -> bool {
-        // NOTE: This does not work with null terminated strings "\x00+ "
-        let encoded_text = (&text.as_bytes()).to_base58();
-        let same_text = (&encoded_text.as_bytes())
+    fn encode_bytes_should_work(bytes: Vec<u8>) -> bool {
+        encode(&bytes) == bytes.to_base58()
+    }
+
+    #[quickcheck]
+    fn encode_zero_prefixed_bytes_should_work(zero_count: u8, bytes: Vec<u8>) -> bool {
+        let mut prefixed_bytes = vec![0; usize::from(zero_count % 8)];
+        prefixed_bytes.extend(bytes);
+
+        encode(&prefixed_bytes) == prefixed_bytes.to_base58()
+    }
+
+    #[quickcheck]
+    fn decode_zero_prefixed_bytes_should_work(zero_count: u8, bytes: Vec<u8>) -> bool {
+        let mut prefixed_bytes = vec![0; usize::from(zero_count % 8)];
+        prefixed_bytes.extend(bytes);
+
+        let encoded_text = prefixed_bytes.to_base58();
+
+        decode(&encoded_text).ok() == encoded_text.from_base58().ok()
+    }
+
+    #[quickcheck]
+    fn encode_decode_should_work(bytes: Vec<u8>) -> bool {
+        decode(encode(&bytes)) == Ok(bytes)
+    }
+
+    #[test]
+    fn large_inputs_should_work() {
+        let plain_bytes: Vec<u8> = (0..4096_u32).map(|index| (index * 7 + 13) as u8).collect();
+        let encoded_text = plain_bytes.to_base58();
+
+        assert_eq!(encoded_text, encode(&plain_bytes));
+        assert_eq!(Ok(plain_bytes), decode(&encoded_text));
+    }
+
+    #[test]
+    fn static_slice_examples_should_work() {
+        let mut output = [0; 32];
+
+        assert_eq!(Ok(15), encode_to_slice("hello world", &mut output));
+        assert_eq!(b"StV1DL6CwTryKyV", &output[..15]);
+
+        assert_eq!(Ok(11), decode_to_slice("StV1DL6CwTryKyV", &mut output));
+        assert_eq!(b"hello world", &output[..11]);
+
+        assert_eq!(Ok(3), encode_to_slice([0, 0, 0], &mut output[..3]));
+        assert_eq!(b"111", &output[..3]);
+
+        assert_eq!(
+            Err(EncodeError::BufferTooSmall),
+            encode_to_slice("hello world", &mut output[..14])
+        );
+        assert_eq!(
+            Err(DecodeError::BufferTooSmall),
+            decode_to_slice("StV1DL6CwTryKyV", &mut output[..10])
+        );
+        assert_eq!(
+            Err(DecodeError::BufferTooSmall),
+            decode_to_slice("111", &mut output[..2])
+        );
+    }
+
+    #[test]
+    fn max_encoded_len_should_match_longest_encodings() {
+        for length in 0..=256 {
+            assert_eq!(max_encoded_len(length), encode(vec![0xff; length]).len());
+        }
+    }
+
+    #[quickcheck]
+    fn max_lengths_should_be_enough(bytes: Vec<u8>) -> bool {
+        let encoded_text = encode(&bytes);
+
+        let mut max_output = vec![0; max_encoded_len(bytes.len())];
+
+        encoded_text.len() <= max_encoded_len(bytes.len())
+            && encode_to_slice(&bytes, &mut max_output) == Ok(encoded_text.len())
+            && bytes.len() <= max_decoded_len(encoded_text.len())
+    }
+
+    #[quickcheck]
+    fn encode_to_exact_slice_should_work(bytes: Vec<u8>) -> bool {
+        let encoded_text = encode(&bytes);
+
+        let mut exact_output = vec![0; encoded_text.len()];
+        let mut short_output = vec![0; encoded_text.len().saturating_sub(1)];
+
+        encode_to_slice(&bytes, &mut exact_output) == Ok(encoded_text.len())
+            && exact_output == encoded_text.as_bytes()
+            && (encoded_text.is_empty()
+                || encode_to_slice(&bytes, &mut short_output) == Err(EncodeError::BufferTooSmall))
+    }
+
+    #[quickcheck]
+    fn decode_to_exact_slice_should_work(bytes: Vec<u8>) -> bool {
+        let encoded_text = encode(&bytes);
+
+        let mut exact_output = vec![0; bytes.len()];
+        let mut short_output = vec![0; bytes.len().saturating_sub(1)];
+
+        decode_to_slice(&encoded_text, &mut exact_output) == Ok(bytes.len())
+            && exact_output == bytes
+            && (bytes.is_empty()
+                || decode_to_slice(&encoded_text, &mut short_output)
+                    == Err(DecodeError::BufferTooSmall))
+    }
+
+    #[test]
+    fn static_alphabet_examples_should_work() {
+        assert_eq!(
+            "StV1DL6CwTryKyV",
+            encode_with_alphabet("hello world", &Alphabet::BITCOIN)
+        );
+        assert_eq!(
+            "rStVrDLaUATiyKyV",
+            encode_with_alphabet("\0hello world", &Alphabet::RIPPLE)
+        );
+        assert_eq!(
+            "rTu1dk6cWsRYjYu",
+            encode_with_alphabet("hello world", &Alphabet::FLICKR)
+        );
+
+        assert_eq!(
+            Ok(b"hello world".to_vec()),
+            decode_with_alphabet("rTu1dk6cWsRYjYu", &Alphabet::FLICKR)
+        );
+        assert_eq!(
+            Err(DecodeError::InvalidCharacter {
+                character: '0',
+                index: 0
+            }),
+            decode_with_alphabet("0", &Alphabet::RIPPLE)
+        );
+    }
+
+    #[quickcheck]
+    fn encode_with_alphabet_should_translate(bytes: Vec<u8>) -> bool {
+        let translate = |alphabet: &Alphabet| -> String {
+            encode(&bytes)
+                .bytes()
+                .map(|ch| Alphabet::BITCOIN.base().value(ch).unwrap())
+                .map(|value| char::from(alphabet.base().character(value)))
+                .collect()
+        };
+
+        [Alphabet::RIPPLE, Alphabet::FLICKR]
+            .iter()
+            .all(|alphabet| encode_with_alphabet(&bytes, alphabet) == translate(alphabet))
+    }
+
+    #[quickcheck]
+    fn encode_decode_with_alphabet_should_work(bytes: Vec<u8>) -> bool {
+        [Alphabet::BITCOIN, Alphabet::RIPPLE, Alphabet::FLICKR]
+            .iter()
+            .all(|alphabet| {
+                decode_with_alphabet(encode_with_alphabet(&bytes, alphabet), alphabet)
+                    == Ok(bytes.clone())
+            })
+    }
+
+    #[test]
+    fn static_validate_examples_should_work() {
+        assert!(is_valid(""));
+        assert!(is_valid("StV1DL6CwTryKyV"));
+        assert!(!is_valid("StV1DL6CwTryKyV0"));
+
+        assert_eq!(Ok(()), validate("11ZiCa"));
+        assert_eq!(
+            Err(DecodeError::InvalidCharacter {
+                character: 'l',
+                index: 4
+            }),
+            validate("StV1l")
+        );
+        assert_eq!(
+            Err(DecodeError::InvalidCharacter {
+                character: 'é',
+                index: 2
+            }),
+            validate("11é")
+        );
+        assert_eq!(Ok(()), validate_with_alphabet("rpshna", &Alphabet::RIPPLE));
+        assert_eq!(
+            Err(DecodeError::InvalidCharacter {
+                character: '0',
+                index: 1
+            }),
+            validate_with_alphabet("r0", &Alphabet::RIPPLE)
+        );
+    }
+
+    #[quickcheck]
+    fn validate_should_match_decode(text: String) -> bool {
+        [Alphabet::BITCOIN, Alphabet::RIPPLE, Alphabet::FLICKR]
+            .iter()
+            .all(|alphabet| {
+                validate_with_alphabet(&text, alphabet)
+                    == decode_with_alphabet(&text, alphabet).map(|_| ())
+            })
+    }
+
+    #[quickcheck]
+    fn decode_string_should_work(text: String) -> bool {
+        let encoded_text = text.as_bytes().to_base58();
+        let same_text = encoded_text
             .from_base58()
             .ok()
             .and_then(|value| String::from_utf8(value).ok());
 
-        decode(&encode(&text)) == same_text
+        decode_string(&encode(&text)).ok() == same_text
+    }
+
+    #[test]
+    fn decode_string_should_fail_on_non_utf8_output() {
+        assert_eq!(
+            Ok(String::from("hello world")),
+            decode_string("StV1DL6CwTryKyV")
+        );
+        assert_eq!(
+            Err(DecodeError::NonUtf8Output { valid_up_to: 1 }),
+            decode_string(&encode([0x61, 0xff, 0x61]))
+        );
     }
 }
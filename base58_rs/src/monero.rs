@@ -0,0 +1,167 @@
+use alloc::{string::String, vec::Vec};
+
+use super::{Alphabet, DecodeError};
+
+pub const BLOCK_LENGTH: usize = 8;
+pub const ENCODED_BLOCK_LENGTH: usize = 11;
+
+const ENCODED_BLOCK_LENGTHS: [usize; BLOCK_LENGTH + 1] = [0, 2, 3, 5, 6, 7, 9, 10, 11];
+
+pub fn encode<T: AsRef<[u8]>>(input: T) -> String {
+    encode_with_alphabet(input, &Alphabet::BITCOIN)
+}
+
+pub fn encode_with_alphabet<T: AsRef<[u8]>>(input: T, alphabet: &Alphabet) -> String {
+    let plain_bytes = input.as_ref();
+
+    let mut encoded_text = String::with_capacity(
+        plain_bytes.len() / BLOCK_LENGTH * ENCODED_BLOCK_LENGTH + ENCODED_BLOCK_LENGTH,
+    );
+
+    for block in plain_bytes.chunks(BLOCK_LENGTH) {
+        let mut value = block
+            .iter()
+            .fold(0_u64, |acc, &byte| (acc << 8) | u64::from(byte));
+
+        let encoded_length = ENCODED_BLOCK_LENGTHS[block.len()];
+        let mut encoded_block = [alphabet.base().character(0); ENCODED_BLOCK_LENGTH];
+
+        for ch in encoded_block[..encoded_length].iter_mut().rev() {
+            *ch = alphabet.base().character((value % 58) as u8);
+            value /= 58;
+        }
+
+        encoded_text.extend(
+            encoded_block[..encoded_length]
+                .iter()
+                .map(|&ch| char::from(ch)),
+        );
+    }
+
+    encoded_text
+}
+
+pub fn decode<T: AsRef<[u8]>>(input: T) -> Result<Vec<u8>, DecodeError> {
+    decode_with_alphabet(input, &Alphabet::BITCOIN)
+}
+
+pub fn decode_with_alphabet<T: AsRef<[u8]>>(
+    input: T,
+    alphabet: &Alphabet,
+) -> Result<Vec<u8>, DecodeError> {
+    let encoded_bytes = input.as_ref();
+
+    let mut plain_bytes = Vec::with_capacity(
+        encoded_bytes.len() / ENCODED_BLOCK_LENGTH * BLOCK_LENGTH + BLOCK_LENGTH,
+    );
+
+    for (block_index, encoded_block) in encoded_bytes.chunks(ENCODED_BLOCK_LENGTH).enumerate() {
+        let index = block_index * ENCODED_BLOCK_LENGTH;
+
+        let block_length = ENCODED_BLOCK_LENGTHS
+            .iter()
+            .position(|&length| length == encoded_block.len())
+            .ok_or(DecodeError::InvalidLength)?;
+
+        let mut value = 0_u64;
+
+        for (offset, &ch) in encoded_block.iter().enumerate() {
+            let digit = alphabet
+                .base()
+                .value(ch)
+                .ok_or_else(|| DecodeError::invalid_character(encoded_bytes, index + offset))?;
+
+            value = value
+                .checked_mul(58)
+                .and_then(|value| value.checked_add(u64::from(digit)))
+                .ok_or(DecodeError::BlockOverflow { index })?;
+        }
+
+        if block_length < BLOCK_LENGTH && value >> (8 * block_length) != 0 {
+            return Err(DecodeError::BlockOverflow { index });
+        }
+
+        plain_bytes.extend_from_slice(&value.to_be_bytes()[BLOCK_LENGTH - block_length..]);
+    }
+
+    Ok(plain_bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec;
+
+    const DONATION_ADDRESS: &str = "44AFFq5kSiGBoZ4NMDwYtN18obc8AemS33DBLWs3H7otXft3XjrpDtQGv7SqSsaBYBb98uNbr2VBBEt7f2wfn3RVGQBEP3A";
+
+    #[test]
+    fn static_block_examples_should_work() {
+        let examples: [(&[u8], &str); 9] = [
+            (&[0x00], "11"),
+            (&[0x39], "1z"),
+            (&[0xff], "5Q"),
+            (&[0x00, 0x39], "11z"),
+            (&[0xff, 0xff], "LUv"),
+            (&[0xff; 8], "jpXCZedGfVQ"),
+            (
+                &[0x06, 0x15, 0x60, 0x13, 0x76, 0x28, 0x79, 0xf7],
+                "22222222222",
+            ),
+            (
+                &[0x05, 0xe0, 0x22, 0xba, 0x37, 0x4b, 0x2a, 0x00],
+                "1z111111111",
+            ),
+            (
+                &[
+                    0x06, 0x15, 0x60, 0x13, 0x76, 0x28, 0x79, 0xf7, 0xff, 0xff, 0xff, 0xff, 0xff,
+                ],
+                "22222222222VtB5VXc",
+            ),
+        ];
+
+        for (plain_bytes, encoded_text) in examples.iter() {
+            assert_eq!(*encoded_text, encode(plain_bytes));
+            assert_eq!(Ok(plain_bytes.to_vec()), decode(encoded_text));
+        }
+
+        assert_eq!("", encode(b""));
+        assert_eq!(Ok(vec![]), decode(""));
+    }
+
+    #[test]
+    fn address_examples_should_work() {
+        let plain_bytes = decode(DONATION_ADDRESS).unwrap();
+
+        assert_eq!(69, plain_bytes.len());
+        assert_eq!(0x12, plain_bytes[0]);
+        assert_eq!(DONATION_ADDRESS, encode(&plain_bytes));
+    }
+
+    #[test]
+    fn invalid_examples_should_fail() {
+        assert_eq!(Err(DecodeError::InvalidLength), decode("1"));
+        assert_eq!(Err(DecodeError::InvalidLength), decode("111111111111111"));
+        assert_eq!(Err(DecodeError::BlockOverflow { index: 0 }), decode("zz"));
+        assert_eq!(
+            Err(DecodeError::BlockOverflow { index: 11 }),
+            decode("11111111111zzzzzzzzzzz")
+        );
+        assert_eq!(
+            Err(DecodeError::InvalidCharacter {
+                character: '0',
+                index: 12
+            }),
+            decode("1111111111110")
+        );
+    }
+
+    #[quickcheck]
+    fn encode_decode_should_work(bytes: Vec<u8>) -> bool {
+        let encoded_text = encode(&bytes);
+
+        encoded_text.len()
+            == bytes.len() / BLOCK_LENGTH * ENCODED_BLOCK_LENGTH
+                + ENCODED_BLOCK_LENGTHS[bytes.len() % BLOCK_LENGTH]
+            && decode(&encoded_text) == Ok(bytes)
+    }
+}
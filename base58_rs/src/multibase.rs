@@ -0,0 +1,479 @@
+use alloc::{string::String, vec::Vec};
+use core::fmt;
+use core::str;
+#[cfg(feature = "std")]
+use std::error::Error;
+
+use super::base32::Base32;
+use super::{Alphabet, BaseN, DecodeError};
+
+const BASE2: &[u8] = b"01";
+const BASE8: &[u8] = b"01234567";
+const BASE16: &[u8] = b"0123456789abcdef";
+const BASE16_UPPER: &[u8] = b"0123456789ABCDEF";
+
+static BASE10: BaseN = BaseN::from_characters(b"0123456789");
+
+static BASE32: Base32 = Base32::from_characters(*b"abcdefghijklmnopqrstuvwxyz234567", false);
+static BASE32_UPPER: Base32 = Base32::STANDARD_NO_PAD;
+static BASE32_PAD: Base32 = BASE32.with_padding(true);
+static BASE32_PAD_UPPER: Base32 = Base32::STANDARD;
+
+static BASE32_HEX: Base32 = Base32::from_characters(*b"0123456789abcdefghijklmnopqrstuv", false);
+static BASE32_HEX_UPPER: Base32 = Base32::HEX_NO_PAD;
+static BASE32_HEX_PAD: Base32 = BASE32_HEX.with_padding(true);
+static BASE32_HEX_PAD_UPPER: Base32 = Base32::HEX;
+
+static BASE32_Z: Base32 = Base32::from_characters(*b"ybndrfg8ejkmcpqxot1uwisza345h769", false);
+
+static BASE36: BaseN = BaseN::BASE36;
+static BASE36_UPPER: BaseN = BaseN::from_characters(b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ");
+
+static BASE58_BTC: Alphabet = Alphabet::BITCOIN;
+static BASE58_FLICKR: Alphabet = Alphabet::FLICKR;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Base {
+    Identity,
+    Base2,
+    Base8,
+    Base10,
+    Base16,
+    Base16Upper,
+    Base32Hex,
+    Base32HexUpper,
+    Base32HexPad,
+    Base32HexPadUpper,
+    Base32,
+    Base32Upper,
+    Base32Pad,
+    Base32PadUpper,
+    Base32Z,
+    Base36,
+    Base36Upper,
+    Base58Btc,
+    Base58Flickr,
+    Base64,
+    Base64Pad,
+    Base64Url,
+    Base64UrlPad,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MultibaseError {
+    MissingPrefix,
+    UnknownPrefix(char),
+    UnsupportedBase(Base),
+    NonUtf8Identity { valid_up_to: usize },
+    Decode(DecodeError),
+}
+
+const BASES: [Base; 23] = [
+    Base::Identity,
+    Base::Base2,
+    Base::Base8,
+    Base::Base10,
+    Base::Base16,
+    Base::Base16Upper,
+    Base::Base32Hex,
+    Base::Base32HexUpper,
+    Base::Base32HexPad,
+    Base::Base32HexPadUpper,
+    Base::Base32,
+    Base::Base32Upper,
+    Base::Base32Pad,
+    Base::Base32PadUpper,
+    Base::Base32Z,
+    Base::Base36,
+    Base::Base36Upper,
+    Base::Base58Btc,
+    Base::Base58Flickr,
+    Base::Base64,
+    Base::Base64Pad,
+    Base::Base64Url,
+    Base::Base64UrlPad,
+];
+
+impl Base {
+    pub fn from_prefix(prefix: char) -> Option<Base> {
+        BASES.iter().copied().find(|base| base.prefix() == prefix)
+    }
+
+    pub fn prefix(self) -> char {
+        match self {
+            Base::Identity => '\0',
+            Base::Base2 => '0',
+            Base::Base8 => '7',
+            Base::Base10 => '9',
+            Base::Base16 => 'f',
+            Base::Base16Upper => 'F',
+            Base::Base32Hex => 'v',
+            Base::Base32HexUpper => 'V',
+            Base::Base32HexPad => 't',
+            Base::Base32HexPadUpper => 'T',
+            Base::Base32 => 'b',
+            Base::Base32Upper => 'B',
+            Base::Base32Pad => 'c',
+            Base::Base32PadUpper => 'C',
+            Base::Base32Z => 'h',
+            Base::Base36 => 'k',
+            Base::Base36Upper => 'K',
+            Base::Base58Btc => 'z',
+            Base::Base58Flickr => 'Z',
+            Base::Base64 => 'm',
+            Base::Base64Pad => 'M',
+            Base::Base64Url => 'u',
+            Base::Base64UrlPad => 'U',
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Base::Identity => "identity",
+            Base::Base2 => "base2",
+            Base::Base8 => "base8",
+            Base::Base10 => "base10",
+            Base::Base16 => "base16",
+            Base::Base16Upper => "base16upper",
+            Base::Base32Hex => "base32hex",
+            Base::Base32HexUpper => "base32hexupper",
+            Base::Base32HexPad => "base32hexpad",
+            Base::Base32HexPadUpper => "base32hexpadupper",
+            Base::Base32 => "base32",
+            Base::Base32Upper => "base32upper",
+            Base::Base32Pad => "base32pad",
+            Base::Base32PadUpper => "base32padupper",
+            Base::Base32Z => "base32z",
+            Base::Base36 => "base36",
+            Base::Base36Upper => "base36upper",
+            Base::Base58Btc => "base58btc",
+            Base::Base58Flickr => "base58flickr",
+            Base::Base64 => "base64",
+            Base::Base64Pad => "base64pad",
+            Base::Base64Url => "base64url",
+            Base::Base64UrlPad => "base64urlpad",
+        }
+    }
+
+    fn codec(self) -> Option<Codec> {
+        match self {
+            Base::Identity => Some(Codec::Identity),
+            Base::Base2 => Some(Codec::Bits(BASE2, 1)),
+            Base::Base8 => Some(Codec::Bits(BASE8, 3)),
+            Base::Base10 => Some(Codec::Radix(&BASE10)),
+            Base::Base16 => Some(Codec::Bits(BASE16, 4)),
+            Base::Base16Upper => Some(Codec::Bits(BASE16_UPPER, 4)),
+            Base::Base32Hex => Some(Codec::Base32(&BASE32_HEX)),
+            Base::Base32HexUpper => Some(Codec::Base32(&BASE32_HEX_UPPER)),
+            Base::Base32HexPad => Some(Codec::Base32(&BASE32_HEX_PAD)),
+            Base::Base32HexPadUpper => Some(Codec::Base32(&BASE32_HEX_PAD_UPPER)),
+            Base::Base32 => Some(Codec::Base32(&BASE32)),
+            Base::Base32Upper => Some(Codec::Base32(&BASE32_UPPER)),
+            Base::Base32Pad => Some(Codec::Base32(&BASE32_PAD)),
+            Base::Base32PadUpper => Some(Codec::Base32(&BASE32_PAD_UPPER)),
+            Base::Base32Z => Some(Codec::Base32(&BASE32_Z)),
+            Base::Base36 => Some(Codec::Radix(&BASE36)),
+            Base::Base36Upper => Some(Codec::Radix(&BASE36_UPPER)),
+            Base::Base58Btc => Some(Codec::Radix(BASE58_BTC.base())),
+            Base::Base58Flickr => Some(Codec::Radix(BASE58_FLICKR.base())),
+            _ => None,
+        }
+    }
+}
+
+enum Codec {
+    Identity,
+    Bits(&'static [u8], u32),
+    Radix(&'static BaseN),
+    Base32(&'static Base32),
+}
+
+impl Codec {
+    fn encode(&self, input: &[u8]) -> Result<String, MultibaseError> {
+        match self {
+            Codec::Identity => str::from_utf8(input).map(String::from).map_err(|error| {
+                MultibaseError::NonUtf8Identity {
+                    valid_up_to: error.valid_up_to(),
+                }
+            }),
+            Codec::Bits(characters, bits) => Ok(encode_bits(input, characters, *bits)),
+            Codec::Radix(base) => match base.encode(input) {
+                Ok(encoded_text) => Ok(encoded_text),
+                Err(_) => unreachable!("multibase alphabets are ASCII"),
+            },
+            Codec::Base32(base) => Ok(base.encode(input)),
+        }
+    }
+
+    fn decode(&self, input: &str) -> Result<Vec<u8>, DecodeError> {
+        match self {
+            Codec::Identity => Ok(input.as_bytes().to_vec()),
+            Codec::Bits(characters, bits) => decode_bits(input.as_bytes(), characters, *bits),
+            Codec::Radix(base) => base.decode(input),
+            Codec::Base32(base) => base.decode(input),
+        }
+    }
+}
+
+// NOTE: Base2, Base8 and Base16 split the input into groups of bits like RFC 4648 rather than
+// converting it as a number, and pad the last symbol with zero bits instead of padding characters.
+fn encode_bits(plain_bytes: &[u8], characters: &[u8], bits: u32) -> String {
+    let mask = (1 << bits) - 1;
+
+    let mut encoded_text = String::with_capacity((plain_bytes.len() * 8).div_ceil(bits as usize));
+    let mut buffer = 0_u32;
+    let mut bit_count = 0;
+
+    for &byte in plain_bytes {
+        buffer = (buffer << 8) | u32::from(byte);
+        bit_count += 8;
+
+        while bit_count >= bits {
+            bit_count -= bits;
+            encoded_text.push(char::from(
+                characters[((buffer >> bit_count) & mask) as usize],
+            ));
+        }
+
+        buffer &= (1 << bit_count) - 1;
+    }
+
+    if bit_count > 0 {
+        encoded_text.push(char::from(
+            characters[(buffer << (bits - bit_count)) as usize],
+        ));
+    }
+
+    encoded_text
+}
+
+fn decode_bits(encoded_bytes: &[u8], characters: &[u8], bits: u32) -> Result<Vec<u8>, DecodeError> {
+    let mut plain_bytes = Vec::with_capacity(encoded_bytes.len() * bits as usize / 8);
+
+    let mut buffer = 0_u32;
+    let mut bit_count = 0;
+
+    for (index, &ch) in encoded_bytes.iter().enumerate() {
+        let value = characters
+            .iter()
+            .position(|&character| character == ch)
+            .ok_or_else(|| DecodeError::invalid_character(encoded_bytes, index))?;
+
+        buffer = (buffer << bits) | value as u32;
+        bit_count += bits;
+
+        if bit_count >= 8 {
+            bit_count -= 8;
+            plain_bytes.push((buffer >> bit_count) as u8);
+            buffer &= (1 << bit_count) - 1;
+        }
+    }
+
+    if bit_count >= bits {
+        return Err(DecodeError::InvalidLength);
+    }
+
+    if buffer != 0 {
+        return Err(DecodeError::invalid_character(
+            encoded_bytes,
+            encoded_bytes.len() - 1,
+        ));
+    }
+
+    Ok(plain_bytes)
+}
+
+pub fn encode<T: AsRef<[u8]>>(base: Base, input: T) -> Result<String, MultibaseError> {
+    let codec = base.codec().ok_or(MultibaseError::UnsupportedBase(base))?;
+
+    let mut encoded_text = String::new();
+    encoded_text.push(base.prefix());
+    encoded_text.push_str(&codec.encode(input.as_ref())?);
+
+    Ok(encoded_text)
+}
+
+pub fn decode<T: AsRef<str>>(input: T) -> Result<(Base, Vec<u8>), MultibaseError> {
+    let encoded_text = input.as_ref();
+
+    let prefix = encoded_text
+        .chars()
+        .next()
+        .ok_or(MultibaseError::MissingPrefix)?;
+    let base = Base::from_prefix(prefix).ok_or(MultibaseError::UnknownPrefix(prefix))?;
+    let codec = base.codec().ok_or(MultibaseError::UnsupportedBase(base))?;
+
+    let plain_bytes =
+        codec
+            .decode(&encoded_text[prefix.len_utf8()..])
+            .map_err(|error| match error {
+                DecodeError::InvalidCharacter { character, index } => {
+                    DecodeError::InvalidCharacter {
+                        character,
+                        index: index + prefix.len_utf8(),
+                    }
+                }
+                error => error,
+            })?;
+
+    Ok((base, plain_bytes))
+}
+
+impl fmt::Display for Base {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl fmt::Display for MultibaseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            MultibaseError::MissingPrefix => write!(f, "missing multibase prefix"),
+            MultibaseError::UnknownPrefix(prefix) => {
+                write!(f, "unknown multibase prefix {:?}", prefix)
+            }
+            MultibaseError::UnsupportedBase(base) => write!(f, "unsupported base {}", base),
+            MultibaseError::NonUtf8Identity { valid_up_to } => write!(
+                f,
+                "identity input is not valid UTF-8 after byte {}",
+                valid_up_to
+            ),
+            MultibaseError::Decode(error) => error.fmt(f),
+        }
+    }
+}
+
+impl From<DecodeError> for MultibaseError {
+    fn from(error: DecodeError) -> Self {
+        MultibaseError::Decode(error)
+    }
+}
+
+#[cfg(feature = "std")]
+impl Error for MultibaseError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            MultibaseError::Decode(error) => Some(error),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn static_examples_should_work() {
+        let examples = [
+            (Base::Identity, "\0yes mani !"),
+            (
+                Base::Base2,
+                "001111001011001010111001100100000011011010110000101101110011010010010000000100001",
+            ),
+            (Base::Base8, "7362625631006654133464440102"),
+            (Base::Base10, "9573277761329450583662625"),
+            (Base::Base16, "f796573206d616e692021"),
+            (Base::Base16Upper, "F796573206D616E692021"),
+            (Base::Base32, "bpfsxgidnmfxgsibb"),
+            (Base::Base32Upper, "BPFSXGIDNMFXGSIBB"),
+            (Base::Base32Pad, "cpfsxgidnmfxgsibb"),
+            (Base::Base32PadUpper, "CPFSXGIDNMFXGSIBB"),
+            (Base::Base32Hex, "vf5in683dc5n6i811"),
+            (Base::Base32HexUpper, "VF5IN683DC5N6I811"),
+            (Base::Base32HexPad, "tf5in683dc5n6i811"),
+            (Base::Base32HexPadUpper, "TF5IN683DC5N6I811"),
+            (Base::Base32Z, "hxf1zgedpcfzg1ebb"),
+            (Base::Base36, "k2lcpzo5yikidynfl"),
+            (Base::Base36Upper, "K2LCPZO5YIKIDYNFL"),
+            (Base::Base58Btc, "z7paNL19xttacUY"),
+            (Base::Base58Flickr, "Z7Pznk19XTTzBtx"),
+        ];
+
+        for (base, encoded_text) in examples.iter() {
+            assert_eq!(Ok(String::from(*encoded_text)), encode(*base, "yes mani !"));
+            assert_eq!(Ok((*base, b"yes mani !".to_vec())), decode(encoded_text));
+        }
+
+        assert_eq!(
+            Ok(String::from("z17paNL19xttacUY")),
+            encode(Base::Base58Btc, "\0yes mani !")
+        );
+        assert_eq!(
+            Ok((Base::Base36, b"\0yes mani !".to_vec())),
+            decode("k02lcpzo5yikidynfl")
+        );
+    }
+
+    #[test]
+    fn invalid_examples_should_fail() {
+        assert_eq!(Err(MultibaseError::MissingPrefix), decode(""));
+        assert_eq!(Err(MultibaseError::UnknownPrefix('x')), decode("x123"));
+        assert_eq!(
+            Err(MultibaseError::UnsupportedBase(Base::Base64)),
+            decode("meWVzIG1hbmkgIQ")
+        );
+        assert_eq!(
+            Err(MultibaseError::UnsupportedBase(Base::Base64)),
+            encode(Base::Base64, "yes mani !")
+        );
+        assert_eq!(
+            Err(MultibaseError::Decode(DecodeError::InvalidCharacter {
+                character: '0',
+                index: 3
+            })),
+            decode("z7p0")
+        );
+        assert_eq!(
+            "invalid character '0' at byte 3",
+            decode("z7p0").unwrap_err().to_string()
+        );
+        assert_eq!(
+            Err(MultibaseError::NonUtf8Identity { valid_up_to: 1 }),
+            encode(Base::Identity, [b'a', 0xff])
+        );
+        assert_eq!(
+            Err(MultibaseError::Decode(DecodeError::InvalidLength)),
+            decode("f796")
+        );
+        assert_eq!(
+            Err(MultibaseError::Decode(DecodeError::InvalidLength)),
+            decode("77777")
+        );
+        assert_eq!(
+            Err(MultibaseError::Decode(DecodeError::InvalidCharacter {
+                character: '7',
+                index: 3
+            })),
+            decode("7777")
+        );
+        assert_eq!(
+            Err(MultibaseError::Decode(DecodeError::InvalidCharacter {
+                character: 'A',
+                index: 3
+            })),
+            decode("f79A5")
+        );
+    }
+
+    #[test]
+    fn prefixes_should_be_unique() {
+        for base in BASES.iter() {
+            assert_eq!(Some(*base), Base::from_prefix(base.prefix()));
+        }
+    }
+
+    #[quickcheck]
+    fn encode_decode_should_work(bytes: Vec<u8>) -> bool {
+        BASES
+            .iter()
+            .filter(|base| base.codec().is_some())
+            .all(|&base| match encode(base, &bytes) {
+                Err(MultibaseError::NonUtf8Identity { valid_up_to }) => {
+                    base == Base::Identity && str::from_utf8(&bytes[..valid_up_to]).is_ok()
+                }
+                encoded_text => encoded_text.and_then(decode) == Ok((base, bytes.clone())),
+            })
+    }
+}
@@ -0,0 +1,144 @@
+use alloc::{string::String, vec::Vec};
+use core::convert::TryFrom;
+use core::fmt;
+use core::marker::PhantomData;
+
+use ::serde::de::{self, Deserializer, Visitor};
+use ::serde::{Deserialize, Serialize, Serializer};
+
+use super::{decode, encode};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct Base58<T>(pub T);
+
+impl<T> Base58<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<T> for Base58<T> {
+    fn from(value: T) -> Self {
+        Base58(value)
+    }
+}
+
+impl<T: AsRef<[u8]>> AsRef<[u8]> for Base58<T> {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_ref()
+    }
+}
+
+impl<T: AsRef<[u8]>> fmt::Display for Base58<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(&encode(&self.0))
+    }
+}
+
+impl<T: AsRef<[u8]>> Serialize for Base58<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serialize(&self.0, serializer)
+    }
+}
+
+impl<'de, T: TryFrom<Vec<u8>>> Deserialize<'de> for Base58<T> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize(deserializer).map(Base58)
+    }
+}
+
+pub fn serialize<T: AsRef<[u8]>, S: Serializer>(
+    value: &T,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(&encode(value))
+}
+
+pub fn deserialize<'de, T: TryFrom<Vec<u8>>, D: Deserializer<'de>>(
+    deserializer: D,
+) -> Result<T, D::Error> {
+    deserializer.deserialize_str(Base58Visitor(PhantomData))
+}
+
+struct Base58Visitor<T>(PhantomData<T>);
+
+impl<'de, T: TryFrom<Vec<u8>>> Visitor<'de> for Base58Visitor<T> {
+    type Value = T;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a Base58 string")
+    }
+
+    fn visit_str<E: de::Error>(self, encoded_text: &str) -> Result<T, E> {
+        let plain_bytes = decode(encoded_text).map_err(E::custom)?;
+        let length = plain_bytes.len();
+
+        T::try_from(plain_bytes)
+            .map_err(|_| E::invalid_length(length, &"a Base58 string of the expected length"))
+    }
+
+    fn visit_string<E: de::Error>(self, encoded_text: String) -> Result<T, E> {
+        self.visit_str(&encoded_text)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ::serde::{Deserialize, Serialize};
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Config {
+        #[serde(with = "crate::serde")]
+        key: [u8; 4],
+        #[serde(serialize_with = "serialize", deserialize_with = "deserialize")]
+        payload: Vec<u8>,
+        id: Base58<Vec<u8>>,
+    }
+
+    #[test]
+    fn config_should_round_trip() {
+        let config = Config {
+            key: [0, 1, 2, 3],
+            payload: b"hello world".to_vec(),
+            id: Base58(vec![0xff]),
+        };
+
+        let json = serde_json::to_string(&config).unwrap();
+
+        assert_eq!(
+            r#"{"key":"1Ldp","payload":"StV1DL6CwTryKyV","id":"5Q"}"#,
+            json
+        );
+        assert_eq!(config, serde_json::from_str(&json).unwrap());
+    }
+
+    #[test]
+    fn invalid_fields_should_fail() {
+        let error = serde_json::from_str::<Config>(r#"{"key":"1Ldp","payload":"StV0","id":"5Q"}"#)
+            .unwrap_err();
+
+        assert!(error
+            .to_string()
+            .starts_with("invalid character '0' at byte 3"));
+
+        let error =
+            serde_json::from_str::<Config>(r#"{"key":"5Q","payload":"","id":"5Q"}"#).unwrap_err();
+
+        assert!(error
+            .to_string()
+            .starts_with("invalid length 1, expected a Base58 string of the expected length"));
+
+        let error = serde_json::from_str::<Base58<Vec<u8>>>("42").unwrap_err();
+
+        assert!(error
+            .to_string()
+            .starts_with("invalid type: integer `42`, expected a Base58 string"));
+    }
+
+    #[test]
+    fn display_should_encode() {
+        assert_eq!("StV1DL6CwTryKyV", Base58("hello world").to_string());
+    }
+}
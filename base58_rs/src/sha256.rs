@@ -0,0 +1,220 @@
+pub const DIGEST_LENGTH: usize = 32;
+
+const BLOCK_LENGTH: usize = 64;
+
+const INITIAL_STATE: [u32; 8] = [
+    0x6a09_e667,
+    0xbb67_ae85,
+    0x3c6e_f372,
+    0xa54f_f53a,
+    0x510e_527f,
+    0x9b05_688c,
+    0x1f83_d9ab,
+    0x5be0_cd19,
+];
+
+const ROUND_CONSTANTS: [u32; 64] = [
+    0x428a_2f98,
+    0x7137_4491,
+    0xb5c0_fbcf,
+    0xe9b5_dba5,
+    0x3956_c25b,
+    0x59f1_11f1,
+    0x923f_82a4,
+    0xab1c_5ed5,
+    0xd807_aa98,
+    0x1283_5b01,
+    0x2431_85be,
+    0x550c_7dc3,
+    0x72be_5d74,
+    0x80de_b1fe,
+    0x9bdc_06a7,
+    0xc19b_f174,
+    0xe49b_69c1,
+    0xefbe_4786,
+    0x0fc1_9dc6,
+    0x240c_a1cc,
+    0x2de9_2c6f,
+    0x4a74_84aa,
+    0x5cb0_a9dc,
+    0x76f9_88da,
+    0x983e_5152,
+    0xa831_c66d,
+    0xb003_27c8,
+    0xbf59_7fc7,
+    0xc6e0_0bf3,
+    0xd5a7_9147,
+    0x06ca_6351,
+    0x1429_2967,
+    0x27b7_0a85,
+    0x2e1b_2138,
+    0x4d2c_6dfc,
+    0x5338_0d13,
+    0x650a_7354,
+    0x766a_0abb,
+    0x81c2_c92e,
+    0x9272_2c85,
+    0xa2bf_e8a1,
+    0xa81a_664b,
+    0xc24b_8b70,
+    0xc76c_51a3,
+    0xd192_e819,
+    0xd699_0624,
+    0xf40e_3585,
+    0x106a_a070,
+    0x19a4_c116,
+    0x1e37_6c08,
+    0x2748_774c,
+    0x34b0_bcb5,
+    0x391c_0cb3,
+    0x4ed8_aa4a,
+    0x5b9c_ca4f,
+    0x682e_6ff3,
+    0x748f_82ee,
+    0x78a5_636f,
+    0x84c8_7814,
+    0x8cc7_0208,
+    0x90be_fffa,
+    0xa450_6ceb,
+    0xbef9_a3f7,
+    0xc671_78f2,
+];
+
+pub fn digest(message: &[u8]) -> [u8; DIGEST_LENGTH] {
+    let mut state = INITIAL_STATE;
+
+    let mut blocks = message.chunks_exact(BLOCK_LENGTH);
+
+    for block in &mut blocks {
+        compress(&mut state, block);
+    }
+
+    let remainder = blocks.remainder();
+    let bit_length = (message.len() as u64).wrapping_mul(8);
+
+    let mut last_blocks = [0; BLOCK_LENGTH * 2];
+    last_blocks[..remainder.len()].copy_from_slice(remainder);
+    last_blocks[remainder.len()] = 0x80;
+
+    let last_length = if remainder.len() + 9 > BLOCK_LENGTH {
+        BLOCK_LENGTH * 2
+    } else {
+        BLOCK_LENGTH
+    };
+
+    last_blocks[last_length - 8..last_length].copy_from_slice(&bit_length.to_be_bytes());
+
+    for block in last_blocks[..last_length].chunks_exact(BLOCK_LENGTH) {
+        compress(&mut state, block);
+    }
+
+    let mut hash = [0; DIGEST_LENGTH];
+
+    for (word, hash_bytes) in state.iter().zip(hash.chunks_exact_mut(4)) {
+        hash_bytes.copy_from_slice(&word.to_be_bytes());
+    }
+
+    hash
+}
+
+pub fn double_digest(message: &[u8]) -> [u8; DIGEST_LENGTH] {
+    digest(&digest(message))
+}
+
+fn compress(state: &mut [u32; 8], block: &[u8]) {
+    let mut schedule = [0u32; 64];
+
+    for (word, word_bytes) in schedule.iter_mut().zip(block.chunks_exact(4)) {
+        *word = u32::from_be_bytes([word_bytes[0], word_bytes[1], word_bytes[2], word_bytes[3]]);
+    }
+
+    for index in 16..64 {
+        let previous_15 = schedule[index - 15];
+        let previous_2 = schedule[index - 2];
+
+        let sigma_0 =
+            previous_15.rotate_right(7) ^ previous_15.rotate_right(18) ^ (previous_15 >> 3);
+        let sigma_1 =
+            previous_2.rotate_right(17) ^ previous_2.rotate_right(19) ^ (previous_2 >> 10);
+
+        schedule[index] = schedule[index - 16]
+            .wrapping_add(sigma_0)
+            .wrapping_add(schedule[index - 7])
+            .wrapping_add(sigma_1);
+    }
+
+    let [mut a, mut b, mut c, mut d, mut e, mut f, mut g, mut h] = *state;
+
+    for (round_constant, word) in ROUND_CONSTANTS.iter().zip(schedule.iter()) {
+        let sum_1 = e.rotate_right(6) ^ e.rotate_right(11) ^ e.rotate_right(25);
+        let choice = (e & f) ^ (!e & g);
+        let temp_1 = h
+            .wrapping_add(sum_1)
+            .wrapping_add(choice)
+            .wrapping_add(*round_constant)
+            .wrapping_add(*word);
+
+        let sum_0 = a.rotate_right(2) ^ a.rotate_right(13) ^ a.rotate_right(22);
+        let majority = (a & b) ^ (a & c) ^ (b & c);
+        let temp_2 = sum_0.wrapping_add(majority);
+
+        h = g;
+        g = f;
+        f = e;
+        e = d.wrapping_add(temp_1);
+        d = c;
+        c = b;
+        b = a;
+        a = temp_1.wrapping_add(temp_2);
+    }
+
+    for (word, value) in state.iter_mut().zip([a, b, c, d, e, f, g, h].iter()) {
+        *word = word.wrapping_add(*value);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::vec::Vec;
+
+    use sha2::{Digest, Sha256};
+
+    #[test]
+    fn static_digest_examples_should_work() {
+        assert_eq!(
+            [
+                0xe3, 0xb0, 0xc4, 0x42, 0x98, 0xfc, 0x1c, 0x14, 0x9a, 0xfb, 0xf4, 0xc8, 0x99, 0x6f,
+                0xb9, 0x24, 0x27, 0xae, 0x41, 0xe4, 0x64, 0x9b, 0x93, 0x4c, 0xa4, 0x95, 0x99, 0x1b,
+                0x78, 0x52, 0xb8, 0x55
+            ],
+            digest(b"")
+        );
+        assert_eq!(
+            [
+                0xba, 0x78, 0x16, 0xbf, 0x8f, 0x01, 0xcf, 0xea, 0x41, 0x41, 0x40, 0xde, 0x5d, 0xae,
+                0x22, 0x23, 0xb0, 0x03, 0x61, 0xa3, 0x96, 0x17, 0x7a, 0x9c, 0xb4, 0x10, 0xff, 0x61,
+                0xf2, 0x00, 0x15, 0xad
+            ],
+            digest(b"abc")
+        );
+        assert_eq!(
+            [
+                0x24, 0x8d, 0x6a, 0x61, 0xd2, 0x06, 0x38, 0xb8, 0xe5, 0xc0, 0x26, 0x93, 0x0c, 0x3e,
+                0x60, 0x39, 0xa3, 0x3c, 0xe4, 0x59, 0x64, 0xff, 0x21, 0x67, 0xf6, 0xec, 0xed, 0xd4,
+                0x19, 0xdb, 0x06, 0xc1
+            ],
+            digest(b"abcdbcdecdefdefgefghfghighijhijkijkljklmklmnlmnomnopnopq")
+        );
+    }
+
+    #[quickcheck]
+    fn digest_should_work(message: Vec<u8>) -> bool {
+        digest(&message)[..] == Sha256::digest(&message)[..]
+    }
+
+    #[quickcheck]
+    fn double_digest_should_work(message: Vec<u8>) -> bool {
+        double_digest(&message)[..] == Sha256::digest(&Sha256::digest(&message))[..]
+    }
+}
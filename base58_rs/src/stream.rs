@@ -0,0 +1,365 @@
+use std::error::Error;
+use std::fmt;
+use std::io::{self, Cursor, Read, Write};
+
+use super::{decode_with_alphabet, encode_with_alphabet, Alphabet, DecodeError};
+
+pub const DEFAULT_LIMIT: usize = 64 * 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    Encode,
+    Decode,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LimitExceeded {
+    pub limit: usize,
+}
+
+impl fmt::Display for LimitExceeded {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "stream exceeds the buffer limit of {} bytes", self.limit)
+    }
+}
+
+impl Error for LimitExceeded {}
+
+// NOTE: The writer buffers everything written and converts it in one go in `finish`. Like
+// `BufWriter::into_inner`, `finish` is the only way to see a write error, so a writer dropped
+// without it discards the buffered input instead of writing it out.
+pub struct Base58Writer<W: Write> {
+    inner: W,
+    mode: Mode,
+    alphabet: Alphabet,
+    limit: usize,
+    buffer: Vec<u8>,
+}
+
+impl<W: Write> Base58Writer<W> {
+    pub fn new(inner: W, mode: Mode) -> Self {
+        Self {
+            inner,
+            mode,
+            alphabet: Alphabet::BITCOIN,
+            limit: DEFAULT_LIMIT,
+            buffer: vec![],
+        }
+    }
+
+    pub fn encoder(inner: W) -> Self {
+        Self::new(inner, Mode::Encode)
+    }
+
+    pub fn decoder(inner: W) -> Self {
+        Self::new(inner, Mode::Decode)
+    }
+
+    pub fn with_alphabet(mut self, alphabet: Alphabet) -> Self {
+        self.alphabet = alphabet;
+        self
+    }
+
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    pub fn finish(mut self) -> io::Result<W> {
+        let converted = convert(self.mode, &self.alphabet, &self.buffer).map_err(invalid_data)?;
+
+        self.inner.write_all(&converted)?;
+        self.inner.flush()?;
+
+        Ok(self.inner)
+    }
+}
+
+impl<W: Write> Write for Base58Writer<W> {
+    fn write(&mut self, bytes: &[u8]) -> io::Result<usize> {
+        if self.buffer.len() + bytes.len() > self.limit {
+            return Err(limit_exceeded(self.limit));
+        }
+
+        self.buffer.extend_from_slice(bytes);
+
+        Ok(bytes.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+pub struct Base58Reader<R: Read> {
+    inner: R,
+    mode: Mode,
+    alphabet: Alphabet,
+    limit: usize,
+    output: Option<Output>,
+}
+
+enum Output {
+    Converted(Cursor<Vec<u8>>),
+    LimitExceeded,
+    InvalidData(DecodeError),
+}
+
+impl<R: Read> Base58Reader<R> {
+    pub fn new(inner: R, mode: Mode) -> Self {
+        Self {
+            inner,
+            mode,
+            alphabet: Alphabet::BITCOIN,
+            limit: DEFAULT_LIMIT,
+            output: None,
+        }
+    }
+
+    pub fn encoder(inner: R) -> Self {
+        Self::new(inner, Mode::Encode)
+    }
+
+    pub fn decoder(inner: R) -> Self {
+        Self::new(inner, Mode::Decode)
+    }
+
+    pub fn with_alphabet(mut self, alphabet: Alphabet) -> Self {
+        self.alphabet = alphabet;
+        self
+    }
+
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+}
+
+impl<R: Read> Read for Base58Reader<R> {
+    fn read(&mut self, bytes: &mut [u8]) -> io::Result<usize> {
+        let output = match &mut self.output {
+            Some(output) => output,
+            None => {
+                let mut input = vec![];
+
+                (&mut self.inner)
+                    .take(self.limit as u64 + 1)
+                    .read_to_end(&mut input)?;
+
+                let output = if input.len() > self.limit {
+                    Output::LimitExceeded
+                } else {
+                    match convert(self.mode, &self.alphabet, &input) {
+                        Ok(converted) => Output::Converted(Cursor::new(converted)),
+                        Err(error) => Output::InvalidData(error),
+                    }
+                };
+
+                self.output.get_or_insert(output)
+            }
+        };
+
+        // NOTE: The inner reader is already drained when the conversion fails, so the error is kept
+        // and returned by every later read instead of ending the stream early.
+        match output {
+            Output::Converted(output) => output.read(bytes),
+            Output::LimitExceeded => Err(limit_exceeded(self.limit)),
+            Output::InvalidData(error) => Err(invalid_data(*error)),
+        }
+    }
+}
+
+fn convert(mode: Mode, alphabet: &Alphabet, input: &[u8]) -> Result<Vec<u8>, DecodeError> {
+    match mode {
+        Mode::Encode => Ok(encode_with_alphabet(input, alphabet).into_bytes()),
+        Mode::Decode => {
+            let length = input
+                .iter()
+                .rposition(|byte| !byte.is_ascii_whitespace())
+                .map_or(0, |index| index + 1);
+
+            decode_with_alphabet(&input[..length], alphabet)
+        }
+    }
+}
+
+fn limit_exceeded(limit: usize) -> io::Error {
+    io::Error::other(LimitExceeded { limit })
+}
+
+fn invalid_data(error: DecodeError) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, error)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn writer_should_work() {
+        let mut writer = Base58Writer::encoder(vec![]);
+
+        writer.write_all(b"hello").unwrap();
+        writer.write_all(b" world").unwrap();
+
+        assert_eq!(b"StV1DL6CwTryKyV".to_vec(), writer.finish().unwrap());
+
+        let mut writer = Base58Writer::decoder(vec![]);
+
+        writer.write_all(b"StV1DL6C").unwrap();
+        writer.write_all(b"wTryKyV\n").unwrap();
+
+        assert_eq!(b"hello world".to_vec(), writer.finish().unwrap());
+    }
+
+    #[test]
+    fn writer_should_only_write_on_finish() {
+        let mut output = vec![];
+
+        {
+            let mut writer = Base58Writer::encoder(&mut output).with_alphabet(Alphabet::FLICKR);
+
+            writer.write_all(b"hello world").unwrap();
+        }
+
+        assert!(output.is_empty());
+
+        let mut writer = Base58Writer::encoder(&mut output).with_alphabet(Alphabet::FLICKR);
+
+        writer.write_all(b"hello world").unwrap();
+        writer.finish().unwrap();
+
+        assert_eq!(b"rTu1dk6cWsRYjYu".to_vec(), output);
+    }
+
+    #[test]
+    fn writer_should_report_errors_on_finish() {
+        let mut writer = Base58Writer::decoder(vec![]);
+
+        writer.write_all(b"StV0").unwrap();
+
+        assert_eq!(
+            io::ErrorKind::InvalidData,
+            writer.finish().unwrap_err().kind()
+        );
+    }
+
+    #[test]
+    fn reader_should_work() {
+        let mut encoded_text = String::new();
+
+        Base58Reader::encoder(&b"hello world"[..])
+            .read_to_string(&mut encoded_text)
+            .unwrap();
+
+        assert_eq!("StV1DL6CwTryKyV", encoded_text);
+
+        let mut plain_bytes = vec![];
+
+        Base58Reader::decoder(&b"StV1DL6CwTryKyV\r\n"[..])
+            .read_to_end(&mut plain_bytes)
+            .unwrap();
+
+        assert_eq!(b"hello world".to_vec(), plain_bytes);
+    }
+
+    #[test]
+    fn limits_should_be_enforced() {
+        let mut writer = Base58Writer::encoder(vec![]).with_limit(4);
+
+        writer.write_all(b"1234").unwrap();
+
+        let error = writer.write_all(b"5").unwrap_err();
+
+        assert_eq!(io::ErrorKind::Other, error.kind());
+        assert_eq!(
+            "stream exceeds the buffer limit of 4 bytes",
+            error.to_string()
+        );
+
+        let mut plain_bytes = vec![];
+        let error = Base58Reader::encoder(&b"12345"[..])
+            .with_limit(4)
+            .read_to_end(&mut plain_bytes)
+            .unwrap_err();
+
+        assert_eq!(
+            Some(&LimitExceeded { limit: 4 }),
+            error
+                .get_ref()
+                .and_then(|error| error.downcast_ref::<LimitExceeded>())
+        );
+    }
+
+    #[test]
+    fn invalid_input_should_fail() {
+        let mut plain_bytes = vec![];
+        let error = Base58Reader::decoder(&b"StV0"[..])
+            .read_to_end(&mut plain_bytes)
+            .unwrap_err();
+
+        assert_eq!(io::ErrorKind::InvalidData, error.kind());
+        assert_eq!(
+            Some(&DecodeError::InvalidCharacter {
+                character: '0',
+                index: 3
+            }),
+            error
+                .get_ref()
+                .and_then(|error| error.downcast_ref::<DecodeError>())
+        );
+    }
+
+    #[test]
+    fn reader_errors_should_persist() {
+        let mut reader = Base58Reader::decoder(&b"StV0"[..]);
+        let mut plain_bytes = [0; 16];
+
+        for _ in 0..2 {
+            let error = reader.read(&mut plain_bytes).unwrap_err();
+
+            assert_eq!(io::ErrorKind::InvalidData, error.kind());
+            assert_eq!(
+                Some(&DecodeError::InvalidCharacter {
+                    character: '0',
+                    index: 3
+                }),
+                error
+                    .get_ref()
+                    .and_then(|error| error.downcast_ref::<DecodeError>())
+            );
+        }
+
+        let mut reader = Base58Reader::encoder(&b"12345"[..]).with_limit(4);
+
+        for _ in 0..2 {
+            assert_eq!(
+                io::ErrorKind::Other,
+                reader.read(&mut plain_bytes).unwrap_err().kind()
+            );
+        }
+    }
+
+    #[quickcheck]
+    fn writer_reader_should_work(bytes: Vec<u8>) -> bool {
+        let mut writer = Base58Writer::encoder(vec![]);
+
+        for chunk in bytes.chunks(7) {
+            writer.write_all(chunk).unwrap();
+        }
+
+        let encoded_bytes = writer.finish().unwrap();
+
+        let mut plain_bytes = vec![];
+
+        Base58Reader::decoder(&encoded_bytes[..])
+            .read_to_end(&mut plain_bytes)
+            .unwrap();
+
+        plain_bytes == bytes
+    }
+}
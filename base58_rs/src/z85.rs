@@ -0,0 +1,180 @@
+#[cfg(feature = "alloc")]
+use alloc::{string::String, vec, vec::Vec};
+
+use super::ascii85::write;
+use super::{DecodeError, EncodeError};
+
+const CHARACTERS: [u8; 85] =
+    *b"0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ.-:+=^!/*?&<>()[]{}@%$#";
+
+const INVALID_VALUE: u8 = 0xff;
+
+const VALUES: [u8; 256] = {
+    let mut values = [INVALID_VALUE; 256];
+    let mut index = 0;
+
+    while index < CHARACTERS.len() {
+        values[CHARACTERS[index] as usize] = index as u8;
+        index += 1;
+    }
+
+    values
+};
+
+pub fn encoded_len(input_length: usize) -> usize {
+    input_length / 4 * 5
+}
+
+pub fn decoded_len(input_length: usize) -> usize {
+    input_length / 5 * 4
+}
+
+#[cfg(feature = "alloc")]
+pub fn encode<T: AsRef<[u8]>>(input: T) -> Result<String, EncodeError> {
+    let plain_bytes = input.as_ref();
+
+    let mut encoded_bytes = vec![0; encoded_len(plain_bytes.len())];
+    let length = encode_to_slice(plain_bytes, &mut encoded_bytes)?;
+
+    Ok(encoded_bytes[..length]
+        .iter()
+        .map(|&ch| char::from(ch))
+        .collect())
+}
+
+#[cfg(feature = "alloc")]
+pub fn decode<T: AsRef<[u8]>>(input: T) -> Result<Vec<u8>, DecodeError> {
+    let encoded_bytes = input.as_ref();
+
+    let mut plain_bytes = vec![0; decoded_len(encoded_bytes.len())];
+    let length = decode_to_slice(encoded_bytes, &mut plain_bytes)?;
+
+    plain_bytes.truncate(length);
+
+    Ok(plain_bytes)
+}
+
+pub fn encode_to_slice<T: AsRef<[u8]>>(input: T, output: &mut [u8]) -> Result<usize, EncodeError> {
+    let plain_bytes = input.as_ref();
+
+    if plain_bytes.len() % 4 != 0 {
+        return Err(EncodeError::InvalidLength);
+    }
+
+    let mut length = 0;
+
+    for group in plain_bytes.chunks_exact(4) {
+        let mut value = u32::from_be_bytes([group[0], group[1], group[2], group[3]]);
+        let mut encoded_group = [0; 5];
+
+        for ch in encoded_group.iter_mut().rev() {
+            *ch = CHARACTERS[(value % 85) as usize];
+            value /= 85;
+        }
+
+        write(output, &mut length, &encoded_group).ok_or(EncodeError::BufferTooSmall)?;
+    }
+
+    Ok(length)
+}
+
+pub fn decode_to_slice<T: AsRef<[u8]>>(input: T, output: &mut [u8]) -> Result<usize, DecodeError> {
+    let encoded_bytes = input.as_ref();
+
+    if encoded_bytes.len() % 5 != 0 {
+        return Err(DecodeError::InvalidLength);
+    }
+
+    let mut length = 0;
+
+    for (group_index, group) in encoded_bytes.chunks_exact(5).enumerate() {
+        let mut value = 0_u64;
+
+        for (offset, &ch) in group.iter().enumerate() {
+            let digit = match VALUES[usize::from(ch)] {
+                INVALID_VALUE => {
+                    return Err(DecodeError::invalid_character(
+                        encoded_bytes,
+                        5 * group_index + offset,
+                    ))
+                }
+                digit => digit,
+            };
+
+            value = value * 85 + u64::from(digit);
+        }
+
+        if value > u64::from(u32::MAX) {
+            return Err(DecodeError::BlockOverflow {
+                index: 5 * group_index,
+            });
+        }
+
+        write(output, &mut length, &(value as u32).to_be_bytes())
+            .ok_or(DecodeError::BufferTooSmall)?;
+    }
+
+    Ok(length)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn static_examples_should_work() {
+        let hello_world = [0x86, 0x4f, 0xd2, 0x6f, 0xb5, 0x59, 0xf7, 0x5b];
+
+        assert_eq!(Ok(String::from("HelloWorld")), encode(hello_world));
+        assert_eq!(Ok(String::from("00000")), encode([0; 4]));
+        assert_eq!(Ok(String::from("%nSc0")), encode([0xff; 4]));
+        assert_eq!(Ok(String::new()), encode(b""));
+
+        assert_eq!(Ok(hello_world.to_vec()), decode("HelloWorld"));
+        assert_eq!(Ok(vec![0xff; 4]), decode("%nSc0"));
+    }
+
+    #[test]
+    fn invalid_examples_should_fail() {
+        assert_eq!(Err(EncodeError::InvalidLength), encode(b"abc"));
+        assert_eq!(Err(DecodeError::InvalidLength), decode("Hell"));
+        assert_eq!(
+            Err(DecodeError::BlockOverflow { index: 5 }),
+            decode("Hello%nSc1")
+        );
+        assert_eq!(
+            Err(DecodeError::BlockOverflow { index: 0 }),
+            decode("#####")
+        );
+        assert_eq!(
+            Err(DecodeError::InvalidCharacter {
+                character: '~',
+                index: 7
+            }),
+            decode("HelloWo~ld")
+        );
+
+        let mut output = [0; 7];
+
+        assert_eq!(
+            Err(DecodeError::BufferTooSmall),
+            decode_to_slice("HelloWorld", &mut output)
+        );
+        assert_eq!(
+            Err(EncodeError::BufferTooSmall),
+            encode_to_slice([0; 8], &mut output)
+        );
+    }
+
+    #[quickcheck]
+    fn encode_decode_should_work(bytes: Vec<u8>) -> bool {
+        let aligned_length = bytes.len() / 4 * 4;
+        let aligned_bytes = &bytes[..aligned_length];
+
+        let encoded_text = encode(aligned_bytes).unwrap();
+
+        encoded_text.len() == encoded_len(aligned_length)
+            && decode(&encoded_text) == Ok(aligned_bytes.to_vec())
+            && (aligned_length == bytes.len() || encode(&bytes) == Err(EncodeError::InvalidLength))
+    }
+}
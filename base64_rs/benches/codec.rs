@@ -0,0 +1,82 @@
+extern crate base64;
+extern crate base64_rs;
+#[macro_use]
+extern crate criterion;
+
+use base64_rs::Encodeable;
+use criterion::{BenchmarkId, Criterion, Throughput};
+
+const SIZES: [usize; 3] = [32, 1024, 64 * 1024];
+
+fn sample_bytes(length: usize) -> Vec<u8> {
+    let mut state: u32 = 0x9e37_79b9;
+
+    (0..length)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 17;
+            state ^= state << 5;
+
+            state as u8
+        })
+        .collect()
+}
+
+fn encode_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("encode");
+
+    for &size in SIZES.iter() {
+        let plain_bytes = sample_bytes(size);
+
+        group.throughput(Throughput::Bytes(size as u64));
+
+        group.bench_with_input(
+            BenchmarkId::new("base64_rs", size),
+            &plain_bytes,
+            |b, bytes| b.iter(|| base64_rs::encode(bytes)),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("base64_rs_url_safe", size),
+            &plain_bytes,
+            |b, bytes| {
+                let options =
+                    base64_rs::Options::new().with_alphabet(base64_rs::Alphabet::URL_SAFE);
+
+                b.iter(|| bytes.encode(options))
+            },
+        );
+        group.bench_with_input(
+            BenchmarkId::new("base64", size),
+            &plain_bytes,
+            |b, bytes| b.iter(|| base64::encode(bytes)),
+        );
+    }
+
+    group.finish();
+}
+
+fn decode_benchmark(c: &mut Criterion) {
+    let mut group = c.benchmark_group("decode");
+
+    for &size in SIZES.iter() {
+        let encoded_text = base64_rs::encode(&sample_bytes(size));
+
+        group.throughput(Throughput::Bytes(encoded_text.len() as u64));
+
+        group.bench_with_input(
+            BenchmarkId::new("base64_rs", size),
+            &encoded_text,
+            |b, text| b.iter(|| base64_rs::decode(text)),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("base64", size),
+            &encoded_text,
+            |b, text| b.iter(|| base64::decode(text)),
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, encode_benchmark, decode_benchmark);
+criterion_main!(benches);
@@ -0,0 +1,185 @@
+use super::error::AlphabetError;
+
+pub const ALPHABET_LENGTH: usize = 64;
+
+const PADDING_BYTE: u8 = b'=';
+const INVALID_VALUE: u8 = 0xff;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Alphabet {
+    characters: [u8; ALPHABET_LENGTH],
+    values: [u8; 256],
+}
+
+impl Alphabet {
+    pub const STANDARD: Alphabet = Alphabet::from_characters(
+        *b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/",
+    );
+
+    pub const URL_SAFE: Alphabet = Alphabet::from_characters(
+        *b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_",
+    );
+
+    pub const BCRYPT: Alphabet = Alphabet::from_characters(
+        *b"./ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789",
+    );
+
+    pub const CRYPT: Alphabet = Alphabet::from_characters(
+        *b"./0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz",
+    );
+
+    pub const IMAP_MUTF7: Alphabet = Alphabet::from_characters(
+        *b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+,",
+    );
+
+    pub fn new<T: AsRef<[u8]>>(characters: T) -> Result<Self, AlphabetError> {
+        let characters = characters.as_ref();
+
+        if characters.len() != ALPHABET_LENGTH {
+            return Err(AlphabetError::InvalidLength(characters.len()));
+        }
+
+        let mut alphabet_characters = [0; ALPHABET_LENGTH];
+
+        for (index, &byte) in characters.iter().enumerate() {
+            if !byte.is_ascii_graphic() || byte == PADDING_BYTE {
+                return Err(AlphabetError::InvalidCharacter { index, byte });
+            }
+
+            if characters[..index].contains(&byte) {
+                return Err(AlphabetError::DuplicateCharacter {
+                    index,
+                    character: char::from(byte),
+                });
+            }
+
+            alphabet_characters[index] = byte;
+        }
+
+        Ok(Alphabet::from_characters(alphabet_characters))
+    }
+
+    const fn from_characters(characters: [u8; ALPHABET_LENGTH]) -> Self {
+        let mut values = [INVALID_VALUE; 256];
+        let mut index = 0;
+
+        while index < ALPHABET_LENGTH {
+            values[characters[index] as usize] = index as u8;
+            index += 1;
+        }
+
+        Alphabet { characters, values }
+    }
+
+    pub fn characters(&self) -> &[u8] {
+        &self.characters
+    }
+
+    pub(crate) fn character(&self, value: u8) -> u8 {
+        self.characters[usize::from(value)]
+    }
+
+    pub(crate) fn value(&self, character: u8) -> Option<u8> {
+        match self.values[usize::from(character)] {
+            INVALID_VALUE => None,
+            value => Some(value),
+        }
+    }
+}
+
+impl Default for Alphabet {
+    fn default() -> Self {
+        Alphabet::STANDARD
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn static_alphabets_should_be_valid() {
+        let alphabets = [
+            Alphabet::STANDARD,
+            Alphabet::URL_SAFE,
+            Alphabet::BCRYPT,
+            Alphabet::CRYPT,
+            Alphabet::IMAP_MUTF7,
+        ];
+
+        for alphabet in alphabets.iter() {
+            assert_eq!(Ok(*alphabet), Alphabet::new(alphabet.characters()));
+        }
+    }
+
+    #[test]
+    fn lookup_tables_should_invert_each_other() {
+        let alphabets = [
+            Alphabet::STANDARD,
+            Alphabet::URL_SAFE,
+            Alphabet::BCRYPT,
+            Alphabet::CRYPT,
+            Alphabet::IMAP_MUTF7,
+        ];
+
+        for alphabet in alphabets.iter() {
+            for value in 0..ALPHABET_LENGTH as u8 {
+                assert_eq!(Some(value), alphabet.value(alphabet.character(value)));
+            }
+
+            for byte in 0..=255 {
+                let expected_value = alphabet
+                    .characters()
+                    .iter()
+                    .position(|&ch| ch == byte)
+                    .map(|value| value as u8);
+
+                assert_eq!(expected_value, alphabet.value(byte));
+                assert_eq!(
+                    expected_value.is_none(),
+                    alphabet.values[usize::from(byte)] == INVALID_VALUE
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn invalid_alphabets_should_fail() {
+        let mut characters = Alphabet::STANDARD.characters().to_vec();
+
+        assert_eq!(
+            Err(AlphabetError::InvalidLength(63)),
+            Alphabet::new(&characters[..63])
+        );
+
+        characters[63] = b'=';
+
+        assert_eq!(
+            Err(AlphabetError::InvalidCharacter {
+                index: 63,
+                byte: b'='
+            }),
+            Alphabet::new(&characters)
+        );
+
+        characters[63] = b' ';
+
+        assert_eq!(
+            Err(AlphabetError::InvalidCharacter {
+                index: 63,
+                byte: b' '
+            }),
+            Alphabet::new(&characters)
+        );
+
+        characters[63] = b'A';
+
+        assert_eq!(
+            Err(AlphabetError::DuplicateCharacter {
+                index: 63,
+                character: 'A'
+            }),
+            Alphabet::new(&characters)
+        );
+    }
+}
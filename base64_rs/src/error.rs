@@ -0,0 +1,52 @@
+use std::error::Error;
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlphabetError {
+    InvalidLength(usize),
+    InvalidCharacter { index: usize, byte: u8 },
+    DuplicateCharacter { index: usize, character: char },
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DecodeError {
+    InvalidCharacter { character: char, index: usize },
+    InvalidLength,
+    InvalidPadding { index: usize },
+}
+
+impl fmt::Display for AlphabetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            AlphabetError::InvalidLength(length) => {
+                write!(f, "alphabet has an invalid length of {} characters", length)
+            }
+            AlphabetError::InvalidCharacter { index, byte } => {
+                write!(f, "invalid alphabet byte {:#04x} at index {}", byte, index)
+            }
+            AlphabetError::DuplicateCharacter { index, character } => {
+                write!(
+                    f,
+                    "duplicate alphabet character {:?} at index {}",
+                    character, index
+                )
+            }
+        }
+    }
+}
+
+impl Error for AlphabetError {}
+
+impl fmt::Display for DecodeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DecodeError::InvalidCharacter { character, index } => {
+                write!(f, "invalid character {:?} at byte {}", character, index)
+            }
+            DecodeError::InvalidLength => write!(f, "invalid length"),
+            DecodeError::InvalidPadding { index } => write!(f, "invalid padding at byte {}", index),
+        }
+    }
+}
+
+impl Error for DecodeError {}
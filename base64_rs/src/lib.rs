@@ -1,6 +1,3 @@
-#[macro_use]
-extern crate lazy_static;
-
 #[cfg(test)]
 extern crate quickcheck;
 
@@ -11,6 +8,18 @@ extern crate quickcheck_macros;
 #[cfg(test)]
 extern crate base64;
 
+mod alphabet;
+
 mod encode;
 
 mod decode;
+
+mod error;
+
+mod options;
+
+pub use alphabet::{Alphabet, ALPHABET_LENGTH};
+pub use decode::{decode, decode_with_options};
+pub use encode::{encode, Encodeable, EncodedByte, EncodedStream};
+pub use error::{AlphabetError, DecodeError};
+pub use options::{Options, DEFAULT_OPTIONS};
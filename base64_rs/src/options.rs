@@ -0,0 +1,40 @@
+use super::alphabet::Alphabet;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Options {
+    unpadded: bool,
+    alphabet: Alphabet,
+}
+
+pub const DEFAULT_OPTIONS: Options = Options::new();
+
+impl Options {
+    pub const fn new() -> Self {
+        Options {
+            unpadded: false,
+            alphabet: Alphabet::STANDARD,
+        }
+    }
+
+    pub const fn unpadded(self, unpadded: bool) -> Self {
+        Options { unpadded, ..self }
+    }
+
+    pub const fn with_alphabet(self, alphabet: Alphabet) -> Self {
+        Options { alphabet, ..self }
+    }
+
+    pub fn is_unpadded(&self) -> bool {
+        self.unpadded
+    }
+
+    pub fn alphabet(&self) -> &Alphabet {
+        &self.alphabet
+    }
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        DEFAULT_OPTIONS
+    }
+}